@@ -8,10 +8,12 @@ use std::sync::{Arc, Mutex};
 use chrono::Utc;
 use fs2::FileExt;
 
-use cho_sdk::client::{HttpObserver, HttpRequestEvent, HttpResponseEvent};
+use cho_sdk::client::{HttpObserver, HttpRequestEvent, HttpResponseEvent, PageProgress};
 use cho_sdk::error::{ChoSdkError, Result};
 use cho_sdk::home;
 
+use crate::commands::utils::is_secret_config_key;
+
 const MAX_AUDIT_FIELD_CHARS: usize = 8_192;
 const REDACTED: &str = "[REDACTED]";
 
@@ -196,6 +198,80 @@ impl HttpObserver for AuditLogger {
     }
 }
 
+/// HTTP observer that echoes each outgoing request to stderr before auditing it.
+pub struct RequestEcho {
+    audit: AuditLogger,
+}
+
+impl RequestEcho {
+    /// Wraps the audit logger used for the current run.
+    pub fn new(audit: AuditLogger) -> Self {
+        Self { audit }
+    }
+}
+
+impl HttpObserver for RequestEcho {
+    fn on_request(&self, event: &HttpRequestEvent) -> Result<()> {
+        eprintln!("{}", format_request_echo(event));
+        self.audit.on_request(event)
+    }
+
+    fn on_response(&self, event: &HttpResponseEvent) -> Result<()> {
+        self.audit.on_response(event)
+    }
+}
+
+/// HTTP observer that prints list pagination progress to stderr.
+pub struct ProgressEcho {
+    inner: Arc<dyn HttpObserver>,
+}
+
+impl ProgressEcho {
+    /// Wraps the observer that handles request and response events.
+    pub fn new(inner: Arc<dyn HttpObserver>) -> Self {
+        Self { inner }
+    }
+}
+
+impl HttpObserver for ProgressEcho {
+    fn on_request(&self, event: &HttpRequestEvent) -> Result<()> {
+        self.inner.on_request(event)
+    }
+
+    fn on_response(&self, event: &HttpResponseEvent) -> Result<()> {
+        self.inner.on_response(event)
+    }
+
+    fn on_page(&self, progress: &PageProgress) -> Result<()> {
+        eprintln!("{}", format_progress(progress));
+        self.inner.on_page(progress)
+    }
+}
+
+fn format_progress(progress: &PageProgress) -> String {
+    match progress.total {
+        Some(total) => format!(
+            "fetched {}/{} {} (page {})",
+            progress.items_so_far, total, progress.collection, progress.pages_fetched
+        ),
+        None => format!(
+            "fetched {} {} (page {})",
+            progress.items_so_far, progress.collection, progress.pages_fetched
+        ),
+    }
+}
+
+fn format_request_echo(event: &HttpRequestEvent) -> String {
+    let mut lines = vec![format!("> {} {}", event.method, event.full_url)];
+    lines.extend(
+        event
+            .headers
+            .iter()
+            .map(|(name, value)| format!("> {name}: {value}")),
+    );
+    lines.join("\n")
+}
+
 pub fn audit_unavailable_error(err: ChoSdkError) -> ChoSdkError {
     match err {
         ChoSdkError::Config { message } if message.contains("AUDIT_LOG_UNAVAILABLE") => {
@@ -233,7 +309,7 @@ fn sanitize_argv(argv: &[String]) -> Vec<String> {
         let is_config_set_secret_key = window.len() >= 3
             && window[window.len() - 3] == "config"
             && window[window.len() - 2] == "set"
-            && window[window.len() - 1] == "auth.client_secret";
+            && is_secret_config_key(&window[window.len() - 1]);
 
         if is_config_set_secret_key {
             sanitized.push("[REDACTED]".to_string());
@@ -244,7 +320,7 @@ fn sanitize_argv(argv: &[String]) -> Vec<String> {
             continue;
         }
 
-        if arg == "--client-secret" || arg == "auth.client_secret" {
+        if arg == "--client-secret" || is_secret_config_key(arg) {
             sanitized.push(arg.clone());
             redact_next = true;
             window.push_back(arg.clone());
@@ -344,7 +420,7 @@ fn truncate_audit_field(value: &str, max_chars: usize) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{sanitize_argv, sanitize_payload_for_audit};
+    use super::{PageProgress, format_progress, sanitize_argv, sanitize_payload_for_audit};
 
     fn to_vec(args: &[&str]) -> Vec<String> {
         args.iter().map(|arg| (*arg).to_string()).collect()
@@ -375,6 +451,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sanitize_argv_redacts_profile_secret_value() {
+        let sanitized = sanitize_argv(&to_vec(&[
+            "cho",
+            "config",
+            "set",
+            "profile.client-a.client_secret",
+            "topsecret",
+        ]));
+
+        assert_eq!(
+            sanitized,
+            to_vec(&[
+                "cho",
+                "config",
+                "set",
+                "profile.client-a.client_secret",
+                "[REDACTED]",
+            ])
+        );
+    }
+
     #[test]
     fn sanitize_argv_redacts_inline_config_key_value_form() {
         let sanitized = sanitize_argv(&to_vec(&["cho", "auth.client_secret=topsecret"]));
@@ -406,4 +504,25 @@ mod tests {
         assert!(sanitized.contains("[TRUNCATED"));
         assert!(sanitized.len() < 9_000);
     }
+
+    #[test]
+    fn format_progress_includes_total_when_reported() {
+        let progress = PageProgress {
+            collection: "invoices".to_string(),
+            pages_fetched: 12,
+            items_so_far: 1200,
+            total: Some(5000),
+        };
+        assert_eq!(
+            format_progress(&progress),
+            "fetched 1200/5000 invoices (page 12)"
+        );
+        assert_eq!(
+            format_progress(&PageProgress {
+                total: None,
+                ..progress
+            }),
+            "fetched 1200 invoices (page 12)"
+        );
+    }
 }
@@ -26,7 +26,11 @@ pub enum AuthCommands {
     /// Show auth status.
     Status,
     /// Refresh current token.
-    Refresh,
+    Refresh {
+        /// Attempt the refresh even when the stored refresh token looks expired.
+        #[arg(long)]
+        force: bool,
+    },
     /// Logout and clear stored tokens.
     Logout,
 }
@@ -36,7 +40,7 @@ pub fn tool_name(command: &AuthCommands) -> &'static str {
     match command {
         AuthCommands::Login { .. } => "auth.login",
         AuthCommands::Status => "auth.status",
-        AuthCommands::Refresh => "auth.refresh",
+        AuthCommands::Refresh { .. } => "auth.refresh",
         AuthCommands::Logout => "auth.logout",
     }
 }
@@ -62,8 +66,12 @@ pub async fn run(command: &AuthCommands, ctx: &CliContext, start: Instant) -> Re
             let status = ctx.client().session_status().await;
             ctx.emit_success("auth.status", &status, start)
         }
-        AuthCommands::Refresh => {
-            ctx.client().auth().refresh().await?;
+        AuthCommands::Refresh { force } => {
+            if *force {
+                ctx.client().auth().force_refresh().await?;
+            } else {
+                ctx.client().auth().refresh().await?;
+            }
             let payload = serde_json::json!({ "refreshed": true });
             ctx.emit_success("auth.refresh", &payload, start)
         }
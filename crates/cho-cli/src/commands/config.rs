@@ -8,13 +8,17 @@ use clap::Subcommand;
 use crate::audit::AuditLogger;
 use crate::envelope::{self, OutputFormat};
 
-use super::utils::AppConfig;
+use super::utils::{AppConfig, FlagOverrides, is_secret_config_key};
 
 /// Config subcommands.
 #[derive(Debug, Subcommand)]
 pub enum ConfigCommands {
     /// Show current config.
-    Show,
+    Show {
+        /// Show resolved runtime values with their source (flag/env/file/default).
+        #[arg(long)]
+        effective: bool,
+    },
     /// Set config key/value.
     Set {
         /// Dotted key.
@@ -22,13 +26,17 @@ pub enum ConfigCommands {
         /// Value.
         value: String,
     },
+    /// List named profiles and which one is active.
+    #[command(name = "list-profiles")]
+    ListProfiles,
 }
 
 /// Tool name for subcommand.
 pub fn tool_name(command: &ConfigCommands) -> &'static str {
     match command {
-        ConfigCommands::Show => "config.show",
+        ConfigCommands::Show { .. } => "config.show",
         ConfigCommands::Set { .. } => "config.set",
+        ConfigCommands::ListProfiles => "config.list-profiles",
     }
 }
 
@@ -38,11 +46,20 @@ pub fn run(
     output_format: OutputFormat,
     start: Instant,
     audit: &AuditLogger,
+    flags: FlagOverrides<'_>,
 ) -> Result<()> {
     match command {
-        ConfigCommands::Show => {
+        ConfigCommands::Show { effective } => {
             let config = AppConfig::load()?;
-            let payload = config.as_redacted_json();
+            let payload = if *effective {
+                let config = config.with_profile(flags.profile)?;
+                serde_json::json!({
+                    "path": cho_sdk::home::config_path()?,
+                    "settings": config.resolved_settings(flags)?,
+                })
+            } else {
+                config.as_redacted_json()
+            };
             let output = envelope::emit_success(
                 "config.show",
                 &payload,
@@ -62,7 +79,7 @@ pub fn run(
             let path = config.save()?;
             let payload = serde_json::json!({
                 "key": key,
-                "value": if key == "auth.client_secret" { "[REDACTED]" } else { value },
+                "value": if is_secret_config_key(key) { "[REDACTED]" } else { value },
                 "path": path,
             });
             let output = envelope::emit_success(
@@ -78,5 +95,44 @@ pub fn run(
             envelope::write_stdout(&output);
             Ok(())
         }
+        ConfigCommands::ListProfiles => {
+            let config = AppConfig::load()?;
+            let active = flags.profile.map(str::to_string).or_else(|| {
+                std::env::var("CHO_PROFILE")
+                    .ok()
+                    .filter(|value| !value.trim().is_empty())
+            });
+            let profiles = config
+                .profiles
+                .iter()
+                .map(|(name, profile)| {
+                    serde_json::json!({
+                        "name": name,
+                        "active": active.as_deref() == Some(name.as_str()),
+                        "client_id": profile.client_id,
+                        "base_url": profile.base_url,
+                        "authorize_url": profile.authorize_url,
+                        "token_url": profile.token_url,
+                        "allow_writes": profile.allow_writes,
+                    })
+                })
+                .collect::<Vec<_>>();
+            let payload = serde_json::json!({
+                "active": active,
+                "profiles": profiles,
+            });
+            let output = envelope::emit_success(
+                "config.list-profiles",
+                &payload,
+                start,
+                None,
+                None,
+                None,
+                output_format,
+            );
+            audit.log_command_output("config.list-profiles", &output)?;
+            envelope::write_stdout(&output);
+            Ok(())
+        }
     }
 }
@@ -42,14 +42,19 @@ struct HealthResponse {
 }
 
 /// Runs health checks and returns process exit code.
-pub async fn run(output_format: OutputFormat, start: Instant, audit: &AuditLogger) -> Result<i32> {
+pub async fn run(
+    output_format: OutputFormat,
+    start: Instant,
+    audit: &AuditLogger,
+    profile: Option<&str>,
+) -> Result<i32> {
     let mut checks = Vec::new();
 
     checks.push(check_home());
-    checks.push(check_config());
-    checks.push(check_credentials());
+    checks.push(check_config(profile));
+    checks.push(check_credentials(profile));
     checks.push(check_audit());
-    checks.push(check_auth_token().await);
+    checks.push(check_auth_token(profile).await);
 
     let pass = checks.iter().filter(|c| c.status == "pass").count();
     let warn = checks.iter().filter(|c| c.status == "warn").count();
@@ -110,8 +115,8 @@ fn check_home() -> Check {
     }
 }
 
-fn check_config() -> Check {
-    match AppConfig::load() {
+fn check_config(profile: Option<&str>) -> Check {
+    match AppConfig::load().and_then(|config| config.with_profile(profile)) {
         Ok(_) => Check {
             id: "config",
             label: "Config file",
@@ -131,8 +136,8 @@ fn check_config() -> Check {
     }
 }
 
-fn check_credentials() -> Check {
-    let config = match AppConfig::load() {
+fn check_credentials(profile: Option<&str>) -> Check {
+    let config = match AppConfig::load().and_then(|config| config.with_profile(profile)) {
         Ok(config) => config,
         Err(err) => {
             return Check {
@@ -192,8 +197,8 @@ fn check_audit() -> Check {
     }
 }
 
-async fn check_auth_token() -> Check {
-    let config = match AppConfig::load() {
+async fn check_auth_token(profile: Option<&str>) -> Check {
+    let config = match AppConfig::load().and_then(|config| config.with_profile(profile)) {
         Ok(config) => config,
         Err(err) => {
             return Check {
@@ -229,10 +234,24 @@ async fn check_auth_token() -> Check {
         };
     };
 
+    let sdk_config = match config.sdk_config() {
+        Ok(sdk_config) => sdk_config,
+        Err(err) => {
+            return Check {
+                id: "auth",
+                label: "Auth token",
+                status: "fail",
+                severity: "blocking",
+                detail: err.to_string(),
+                fix: "Fix the selected profile or CHO_HOME".to_string(),
+            };
+        }
+    };
+
     let auth = match AuthManager::new(
         client_id,
         SecretString::new(client_secret.into()),
-        config.sdk_config(),
+        sdk_config.clone(),
     ) {
         Ok(auth) => auth,
         Err(err) => {
@@ -260,7 +279,7 @@ async fn check_auth_token() -> Check {
     }
 
     let client = match FreeAgentClient::builder()
-        .config(config.sdk_config())
+        .config(sdk_config)
         .auth_manager(auth)
         .build()
     {
@@ -3,9 +3,12 @@
 use std::time::Instant;
 
 use cho_sdk::error::Result;
+use cho_sdk::models::Report;
 use clap::Subcommand;
 
+use crate::commands::utils::parse_query_pairs;
 use crate::context::CliContext;
+use crate::output::csv::to_csv;
 
 /// Report subcommands.
 #[derive(Debug, Subcommand)]
@@ -24,6 +27,9 @@ pub enum ReportCommands {
         /// Report date (YYYY-MM-DD).
         #[arg(long)]
         as_at_date: Option<String>,
+        /// Report format carried in the envelope data.
+        #[arg(long, value_enum, default_value_t = ReportFormat::Json)]
+        format: ReportFormat,
     },
     /// Balance sheet opening balances.
     BalanceSheetOpeningBalances,
@@ -50,6 +56,25 @@ pub enum ReportCommands {
         #[arg(long)]
         months: Option<u32>,
     },
+    /// List report endpoints available to `reports get`.
+    List,
+    /// Fetch a report by catalog name or `accounting/...` API path.
+    Get {
+        /// Report name from `reports list` or an `accounting/...` path.
+        report: String,
+        /// Additional query pairs (`key=value`), can be repeated.
+        #[arg(long = "query", value_name = "KEY=VALUE")]
+        query: Vec<String>,
+    },
+}
+
+/// Report data formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ReportFormat {
+    /// FreeAgent JSON response.
+    Json,
+    /// Flattened `level,label,value` CSV rows.
+    Csv,
 }
 
 /// Tool name for report command.
@@ -61,6 +86,8 @@ pub fn tool_name(command: &ReportCommands) -> &'static str {
         ReportCommands::TrialBalance { .. } => "reports.trial-balance",
         ReportCommands::TrialBalanceOpeningBalances => "reports.trial-balance-opening-balances",
         ReportCommands::Cashflow { .. } => "reports.cashflow",
+        ReportCommands::List => "reports.list",
+        ReportCommands::Get { .. } => "reports.get",
     }
 }
 
@@ -77,14 +104,25 @@ pub async fn run(command: &ReportCommands, ctx: &CliContext, start: Instant) ->
                 .await?;
             ctx.emit_success("reports.profit-and-loss", &value, start)
         }
-        ReportCommands::BalanceSheet { as_at_date } => {
+        ReportCommands::BalanceSheet { as_at_date, format } => {
             let mut query = Vec::new();
             maybe_push(&mut query, "as_at_date", as_at_date);
             let value = ctx
                 .client()
                 .get_json("accounting/balance_sheet", &query)
                 .await?;
-            ctx.emit_success("reports.balance-sheet", &value, start)
+            match format {
+                ReportFormat::Json => ctx.emit_success("reports.balance-sheet", &value, start),
+                ReportFormat::Csv => {
+                    let rows = Report::from_response(&value).to_flat_rows();
+                    let data = serde_json::json!({
+                        "format": "csv",
+                        "rows": rows.len().saturating_sub(1),
+                        "content": to_csv(&rows),
+                    });
+                    ctx.emit_success("reports.balance-sheet", &data, start)
+                }
+            }
         }
         ReportCommands::BalanceSheetOpeningBalances => {
             let value = ctx
@@ -130,6 +168,14 @@ pub async fn run(command: &ReportCommands, ctx: &CliContext, start: Instant) ->
             let value = ctx.client().get_json("cashflow", &query).await?;
             ctx.emit_success("reports.cashflow", &value, start)
         }
+        ReportCommands::List => {
+            ctx.emit_success("reports.list", &ctx.client().reports().list(), start)
+        }
+        ReportCommands::Get { report, query } => {
+            let query = parse_query_pairs(query)?;
+            let value = ctx.client().reports().get_by_id(report, &query).await?;
+            ctx.emit_success("reports.get", &value, start)
+        }
     }
 }
 
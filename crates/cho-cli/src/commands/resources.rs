@@ -5,6 +5,7 @@ use std::time::Instant;
 
 use cho_sdk::api::specs::{ResourceSpec, by_name};
 use cho_sdk::error::{ChoSdkError, Result};
+use cho_sdk::models::category::flatten_category_groups;
 use cho_sdk::models::{ListResult, Pagination};
 use clap::{Args, Subcommand};
 use serde_json::{Map, Value};
@@ -13,8 +14,8 @@ use crate::context::CliContext;
 
 use super::resources_helpers::{
     annotate_bank_account_fields, attachment_payload_from_path, bank_account_display_name,
-    encode_path_segment, first_bank_transaction_explanation_id, flatten_category_groups,
-    has_bank_account_filter, infer_item_identifier, list_query, sort_items_by_latest_date,
+    encode_path_segment, first_bank_transaction_explanation_id, has_bank_account_filter,
+    infer_item_identifier, list_query, sort_items_by_latest_date,
 };
 pub use super::resources_sales::{
     credit_notes_tool_name, estimates_tool_name, invoices_tool_name, run_credit_notes,
@@ -28,6 +29,12 @@ pub struct ListArgs {
     /// Built-in `view` filter.
     #[arg(long)]
     pub view: Option<String>,
+    /// Include hidden/inactive records by requesting `view=all`.
+    ///
+    /// Contacts, projects and tasks hide inactive records by default; on
+    /// resources whose default view already returns everything it has no effect.
+    #[arg(long, conflicts_with = "view")]
+    pub include_archived: bool,
     /// Sorting expression.
     #[arg(long)]
     pub sort: Option<String>,
@@ -898,14 +905,13 @@ pub async fn run_bank_transactions(
             .await
         }
         BankTransactionCommands::Delete { id } => {
+            let path = format!("bank_transaction/{}", encode_path_segment(id));
+            if ctx.dry_run() {
+                return ctx.emit_dry_run("bank-transactions.delete", "DELETE", &path, None, start);
+            }
+
             ctx.require_writes_allowed()?;
-            let value = ctx
-                .client()
-                .delete_json(
-                    &format!("bank_transaction/{}", encode_path_segment(id)),
-                    true,
-                )
-                .await?;
+            let value = ctx.client().delete_json(&path, true).await?;
             ctx.emit_success("bank-transactions.delete", &value, start)
         }
         BankTransactionCommands::UploadStatement { bank_account, file } => {
@@ -960,12 +966,16 @@ async fn run_bank_transactions_list(
     start: Instant,
 ) -> Result<()> {
     if has_bank_account_filter(args) {
-        let spec = by_name("bank-transactions").ok_or_else(|| ChoSdkError::Config {
-            message: "Unsupported resource 'bank-transactions'".to_string(),
-        })?;
-        let api = ctx.client().resource(spec);
-        let result = api
-            .list(&list_query(args)?, pagination_from_args(ctx, args))
+        let query = list_query(args)?;
+        let bank_account = query
+            .iter()
+            .find(|(key, value)| key == "bank_account" && !value.trim().is_empty())
+            .map(|(_, value)| value.clone())
+            .unwrap_or_default();
+        let result = ctx
+            .client()
+            .bank_transactions()
+            .list_for_account_with_query(&bank_account, &query, ctx.list_pagination(args.per_page))
             .await?;
         return ctx.emit_list(tool, &result, start);
     }
@@ -1431,11 +1441,7 @@ async fn run_resource_with_spec(
             }
 
             let query = list_query(list_args)?;
-            let mut pagination = ctx.pagination();
-            if let Some(per_page) = list_args.per_page {
-                pagination.per_page = per_page.clamp(1, 100);
-            }
-
+            let pagination = ctx.list_pagination(list_args.per_page);
             let result = api.list(&query, pagination).await?;
             ctx.emit_list(&format!("{}.list", tool_prefix), &result, start)
         }
@@ -1456,6 +1462,19 @@ async fn run_resource_with_spec(
                 });
             }
 
+            if ctx.dry_run() {
+                let payload = read_json_file(file)?;
+                let body = normalize_resource_payload(&payload, spec.singular_key);
+                let path = path_with_query(spec.path, &parse_query_pairs(query)?);
+                return ctx.emit_dry_run(
+                    &format!("{}.create", tool_prefix),
+                    "POST",
+                    &path,
+                    Some(&body),
+                    start,
+                );
+            }
+
             ctx.require_writes_allowed()?;
             let payload = read_json_file(file)?;
             ctx.log_input(&format!("{}.create", tool_prefix), &payload)?;
@@ -1477,6 +1496,20 @@ async fn run_resource_with_spec(
                 });
             }
 
+            if ctx.dry_run() {
+                let payload = read_json_file(file)?;
+                let body = normalize_resource_payload(&payload, spec.singular_key);
+                let target_path = resource_target_path(spec.path, id);
+                let path = path_with_query(&target_path, &parse_query_pairs(query)?);
+                return ctx.emit_dry_run(
+                    &format!("{}.update", tool_prefix),
+                    "PUT",
+                    &path,
+                    Some(&body),
+                    start,
+                );
+            }
+
             ctx.require_writes_allowed()?;
             let payload = read_json_file(file)?;
             ctx.log_input(&format!("{}.update", tool_prefix), &payload)?;
@@ -1499,6 +1532,17 @@ async fn run_resource_with_spec(
                 });
             }
 
+            if ctx.dry_run() {
+                let path = resource_target_path(spec.path, id);
+                return ctx.emit_dry_run(
+                    &format!("{}.delete", tool_prefix),
+                    "DELETE",
+                    &path,
+                    None,
+                    start,
+                );
+            }
+
             ctx.require_writes_allowed()?;
             let value = api.delete(id).await?;
             ctx.emit_success(&format!("{}.delete", tool_prefix), &value, start)
@@ -1746,11 +1790,3 @@ async fn list_bank_resource_across_accounts(
 
     ctx.emit_list(tool, &result, start)
 }
-
-fn pagination_from_args(ctx: &CliContext, args: &ListArgs) -> Pagination {
-    let mut pagination = ctx.pagination();
-    if let Some(per_page) = args.per_page {
-        pagination.per_page = per_page.clamp(1, 100);
-    }
-    pagination
-}
@@ -6,6 +6,7 @@ use std::time::Instant;
 
 use base64::Engine as _;
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use cho_sdk::api::specs::by_name;
 use cho_sdk::error::{ChoSdkError, Result};
 use chrono::{DateTime, NaiveDate};
 use serde_json::{Map, Value};
@@ -132,46 +133,40 @@ pub(super) async fn run_default_additional_text(
 }
 
 pub(super) async fn fetch_pdf_resource(
-    api_path: &str,
+    resource: &str,
     id: &str,
     output: Option<&Path>,
     tool: &str,
     ctx: &CliContext,
     start: Instant,
 ) -> Result<()> {
-    let path = format!("{}/{}/pdf", api_path, encode_path_segment(id));
-    let value = ctx.client().get_json(&path, &[]).await?;
-    let pdf = value.get("pdf").cloned().unwrap_or(value);
-    let encoded = pdf
-        .get("content")
-        .and_then(Value::as_str)
-        .ok_or_else(|| ChoSdkError::Parse {
-            message: format!("Expected '{tool}' response to contain pdf.content"),
-        })?;
-    let bytes = BASE64_STANDARD
-        .decode(encoded)
-        .map_err(|e| ChoSdkError::Parse {
-            message: format!("Invalid base64 in {tool} response: {e}"),
-        })?;
+    let spec = by_name(resource).ok_or_else(|| ChoSdkError::Config {
+        message: format!("Missing {resource} resource spec"),
+    })?;
+    let pdf = ctx.client().resource(spec).get_pdf_document(id).await?;
 
     if let Some(path) = output {
-        std::fs::write(path, &bytes).map_err(|e| ChoSdkError::Config {
+        std::fs::write(path, &pdf.content).map_err(|e| ChoSdkError::Config {
             message: format!("Failed writing PDF output {}: {e}", path.display()),
         })?;
         let payload = serde_json::json!({
             "id": id,
-            "bytes": bytes.len(),
+            "bytes": pdf.content.len(),
             "output": path.display().to_string(),
-            "file_name": pdf.get("file_name"),
-            "content_type": pdf.get("content_type"),
+            "file_name": pdf.file_name,
+            "content_type": pdf.content_type,
         });
         return ctx.emit_success(tool, &payload, start);
     }
 
     let payload = serde_json::json!({
         "id": id,
-        "bytes": bytes.len(),
-        "pdf": pdf,
+        "bytes": pdf.content.len(),
+        "pdf": {
+            "content": BASE64_STANDARD.encode(&pdf.content),
+            "file_name": pdf.file_name,
+            "content_type": pdf.content_type,
+        },
     });
     ctx.emit_success(tool, &payload, start)
 }
@@ -180,6 +175,9 @@ pub(super) fn list_query(args: &ListArgs) -> Result<Vec<(String, String)>> {
     let mut query = parse_query_pairs(&args.query)?;
 
     push_if_some(&mut query, "view", args.view.as_ref());
+    if args.include_archived {
+        query.push(("view".to_string(), "all".to_string()));
+    }
     push_if_some(&mut query, "sort", args.sort.as_ref());
     push_if_some(&mut query, "from_date", args.from_date.as_ref());
     push_if_some(&mut query, "to_date", args.to_date.as_ref());
@@ -352,43 +350,6 @@ pub(super) fn has_bank_account_filter(args: &ListArgs) -> bool {
     })
 }
 
-pub(super) fn flatten_category_groups(value: &serde_json::Value) -> Vec<serde_json::Value> {
-    let Some(object) = value.as_object() else {
-        return Vec::new();
-    };
-
-    let mut out = Vec::new();
-    for (group_name, group_value) in object {
-        if let Some(items) = group_value.as_array() {
-            for item in items {
-                let mut item_value = item.clone();
-                if let serde_json::Value::Object(map) = &mut item_value
-                    && !map.contains_key("category_group")
-                {
-                    map.insert(
-                        "category_group".to_string(),
-                        serde_json::Value::String(group_name.clone()),
-                    );
-                }
-                out.push(item_value);
-            }
-        } else if group_value.is_object() {
-            let mut item_value = group_value.clone();
-            if let serde_json::Value::Object(map) = &mut item_value
-                && !map.contains_key("category_group")
-            {
-                map.insert(
-                    "category_group".to_string(),
-                    serde_json::Value::String(group_name.clone()),
-                );
-            }
-            out.push(item_value);
-        }
-    }
-
-    out
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -398,6 +359,7 @@ mod tests {
     fn has_bank_account_filter_detects_direct_flag() {
         let args = ListArgs {
             view: None,
+            include_archived: false,
             sort: None,
             from_date: None,
             to_date: None,
@@ -417,6 +379,7 @@ mod tests {
     fn has_bank_account_filter_detects_query_pair() {
         let args = ListArgs {
             view: None,
+            include_archived: false,
             sort: None,
             from_date: None,
             to_date: None,
@@ -432,38 +395,6 @@ mod tests {
         assert!(has_bank_account_filter(&args));
     }
 
-    #[test]
-    fn flatten_category_groups_flattens_array_groups() {
-        let value = serde_json::json!({
-            "general_categories": [
-                {
-                    "nominal_code": "051",
-                    "description": "Interest Received"
-                }
-            ]
-        });
-
-        let items = flatten_category_groups(&value);
-        assert_eq!(items.len(), 1);
-        assert_eq!(items[0]["nominal_code"], "051");
-        assert_eq!(items[0]["category_group"], "general_categories");
-    }
-
-    #[test]
-    fn flatten_category_groups_flattens_single_object_groups() {
-        let value = serde_json::json!({
-            "general_categories": {
-                "nominal_code": "051",
-                "description": "Interest Received"
-            }
-        });
-
-        let items = flatten_category_groups(&value);
-        assert_eq!(items.len(), 1);
-        assert_eq!(items[0]["nominal_code"], "051");
-        assert_eq!(items[0]["category_group"], "general_categories");
-    }
-
     #[test]
     fn first_bank_transaction_explanation_id_reads_array_entries() {
         let transaction = serde_json::json!({
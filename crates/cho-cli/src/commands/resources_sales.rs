@@ -177,14 +177,17 @@ pub(crate) async fn fetch_filtered_invoices(
     let query = list_query(&args.list)?;
     let client_filter = args.unpaid_only || args.status.is_some();
 
-    let mut fetch_pagination = if client_filter {
-        Pagination::all()
+    let fetch_pagination = if client_filter {
+        Pagination {
+            per_page: args
+                .list
+                .per_page
+                .map_or(ctx.page_size(), |per_page| per_page.clamp(1, 100)),
+            ..Pagination::all()
+        }
     } else {
-        ctx.pagination()
+        ctx.list_pagination(args.list.per_page)
     };
-    if let Some(per_page) = args.list.per_page {
-        fetch_pagination.per_page = per_page.clamp(1, 100);
-    }
 
     let mut result = api.list(&query, fetch_pagination).await?;
 
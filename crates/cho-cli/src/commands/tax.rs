@@ -421,7 +421,7 @@ pub async fn run_self_assessment(
                 query.push(("updated_since".to_string(), updated_since.clone()));
             }
 
-            let pagination = ctx.pagination();
+            let pagination = ctx.list_pagination(None);
             let result = ctx
                 .client()
                 .list_paginated(
@@ -551,11 +551,7 @@ fn tax_list_result(mut result: ListResult) -> ListResult {
 }
 
 fn pagination_from(ctx: &CliContext, args: &ListArgs) -> Pagination {
-    let mut pagination = ctx.pagination();
-    if let Some(per_page) = args.per_page {
-        pagination.per_page = per_page.clamp(1, 100);
-    }
-    pagination
+    ctx.list_pagination(args.per_page)
 }
 
 fn encode_path_segment(value: &str) -> String {
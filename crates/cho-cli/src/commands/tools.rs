@@ -49,13 +49,13 @@ pub fn run(
             output_format,
         );
         audit.log_command_output("tools.get", &output)?;
-        envelope::write_stdout(&output);
+        envelope::write_error(&output, output_format);
         return Ok(1);
     }
 
     let payload = ToolsPayload {
         version: env!("CARGO_PKG_VERSION"),
-        output_formats: &["json", "toon"],
+        output_formats: &["json", "toon", "bare-json"],
         default_output_format: "json",
         global_flags: GLOBAL_FLAGS,
         tools,
@@ -1,5 +1,6 @@
 //! Shared command helpers.
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use cho_sdk::config::SdkConfig;
@@ -24,6 +25,16 @@ pub struct AppConfig {
     /// Safety section.
     #[serde(default)]
     pub safety: SafetyConfig,
+    /// Named profiles (`[profile.<name>]`) layered over the sections above.
+    #[serde(
+        default,
+        rename = "profile",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub profiles: BTreeMap<String, ProfileConfig>,
+    /// Profile applied by [`AppConfig::with_profile`], if any.
+    #[serde(skip)]
+    pub active_profile: Option<String>,
 }
 
 /// Auth config.
@@ -61,6 +72,8 @@ pub struct SdkConfigFile {
     pub timeout_secs: Option<u64>,
     /// Max retries.
     pub max_retries: Option<u32>,
+    /// Wall-clock retry budget per request in seconds.
+    pub max_retry_duration_secs: Option<u64>,
 }
 
 /// Safety config.
@@ -70,6 +83,29 @@ pub struct SafetyConfig {
     pub allow_writes: bool,
 }
 
+/// Per-profile overrides; unset fields fall back to the base sections.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ProfileConfig {
+    /// OAuth client id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// OAuth client secret.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    /// API base URL (e.g. the sandbox for demo companies).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    /// OAuth authorize URL (derived from `base_url` when unset).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorize_url: Option<String>,
+    /// OAuth token URL (derived from `base_url` when unset).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_url: Option<String>,
+    /// Explicit write opt-in for this profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_writes: Option<bool>,
+}
+
 impl AppConfig {
     /// Loads config from disk.
     pub fn load() -> Result<Self> {
@@ -101,6 +137,78 @@ impl AppConfig {
         Ok(path)
     }
 
+    /// Applies the profile named by `--profile`, else `CHO_PROFILE`.
+    ///
+    /// Without either, the config is returned unchanged. Profile fields
+    /// replace the matching base values; env vars and flags still win.
+    pub fn with_profile(mut self, flag: Option<&str>) -> Result<Self> {
+        let Some(name) = flag.map(str::to_string).or_else(|| {
+            std::env::var("CHO_PROFILE")
+                .ok()
+                .filter(|value| !value.trim().is_empty())
+        }) else {
+            return Ok(self);
+        };
+        validate_profile_name(&name)?;
+
+        let profile = self.profiles.get(&name).cloned().ok_or_else(|| {
+            let known = self.profiles.keys().cloned().collect::<Vec<_>>();
+            ChoSdkError::Config {
+                message: format!(
+                    "Unknown profile '{name}' (configured: {})",
+                    if known.is_empty() {
+                        "none".to_string()
+                    } else {
+                        known.join(", ")
+                    }
+                ),
+            }
+        })?;
+
+        if profile.client_id.is_some() {
+            self.auth.client_id = profile.client_id;
+        }
+        if profile.client_secret.is_some() {
+            self.auth.client_secret = profile.client_secret;
+        }
+        if let Some(base_url) = profile.base_url {
+            // Log in and refresh against the same FreeAgent host the profile calls.
+            let root = format!("{}/", base_url.trim_end_matches('/'));
+            self.sdk.authorize_url = Some(format!("{root}approve_app"));
+            self.sdk.token_url = Some(format!("{root}token_endpoint"));
+            self.sdk.base_url = Some(base_url);
+        }
+        if profile.authorize_url.is_some() {
+            self.sdk.authorize_url = profile.authorize_url;
+        }
+        if profile.token_url.is_some() {
+            self.sdk.token_url = profile.token_url;
+        }
+        if let Some(allow_writes) = profile.allow_writes {
+            self.safety.allow_writes = allow_writes;
+        }
+        self.active_profile = Some(name);
+        Ok(self)
+    }
+
+    /// Profile-specific token file, unless `CHO_TOKEN_FILE` overrides it.
+    ///
+    /// Fails rather than falling back to the shared token file, so one
+    /// profile never reads or overwrites another company's tokens.
+    fn profile_token_file(&self) -> Result<Option<PathBuf>> {
+        if std::env::var_os("CHO_TOKEN_FILE").is_some_and(|value| !value.is_empty()) {
+            return Ok(None);
+        }
+        self.active_profile
+            .as_deref()
+            .map(cho_sdk::home::profile_token_path)
+            .transpose()
+    }
+
+    fn active_profile_config(&self) -> Option<&ProfileConfig> {
+        self.profiles.get(self.active_profile.as_deref()?)
+    }
+
     /// Resolves client id from env > config.
     pub fn resolve_client_id(&self) -> Option<String> {
         std::env::var("CHO_CLIENT_ID")
@@ -128,7 +236,7 @@ impl AppConfig {
     }
 
     /// Builds runtime SDK config.
-    pub fn sdk_config(&self) -> SdkConfig {
+    pub fn sdk_config(&self) -> Result<SdkConfig> {
         let mut config = SdkConfig::default();
 
         if let Some(base_url) = std::env::var("CHO_BASE_URL")
@@ -155,7 +263,16 @@ impl AppConfig {
             config = config.with_max_retries(max_retries);
         }
 
-        config.with_allow_writes(self.safety.allow_writes)
+        if let Some(max_retry_duration_secs) = self.sdk.max_retry_duration_secs {
+            config = config
+                .with_max_retry_duration(std::time::Duration::from_secs(max_retry_duration_secs));
+        }
+
+        if let Some(token_file) = self.profile_token_file()? {
+            config = config.with_token_file(token_file);
+        }
+
+        Ok(config.with_allow_writes(self.safety.allow_writes))
     }
 
     /// Sets dotted key to string value.
@@ -184,10 +301,19 @@ impl AppConfig {
                 })?;
                 self.sdk.max_retries = Some(parsed);
             }
+            "sdk.max_retry_duration_secs" => {
+                let parsed = value.parse::<u64>().map_err(|e| ChoSdkError::Config {
+                    message: format!("sdk.max_retry_duration_secs must be an integer: {e}"),
+                })?;
+                self.sdk.max_retry_duration_secs = Some(parsed);
+            }
             "safety.allow_writes" => {
                 let parsed = parse_bool(value)?;
                 self.safety.allow_writes = parsed;
             }
+            profile_key if profile_key.starts_with("profile.") => {
+                self.set_profile_key(profile_key, value)?;
+            }
             unknown => {
                 return Err(ChoSdkError::Config {
                     message: format!("Unsupported config key '{unknown}'"),
@@ -198,6 +324,47 @@ impl AppConfig {
         Ok(())
     }
 
+    /// Sets `profile.<name>.<field>`, creating the profile on first use.
+    fn set_profile_key(&mut self, key: &str, value: &str) -> Result<()> {
+        let unsupported = || ChoSdkError::Config {
+            message: format!(
+                "Unsupported config key '{key}' (expected profile.<name>.client_id|client_secret|base_url|authorize_url|token_url|allow_writes)"
+            ),
+        };
+        let (name, field) = key
+            .strip_prefix("profile.")
+            .and_then(|rest| rest.rsplit_once('.'))
+            .ok_or_else(unsupported)?;
+        validate_profile_name(name)?;
+        if !matches!(
+            field,
+            "client_id"
+                | "client_secret"
+                | "base_url"
+                | "authorize_url"
+                | "token_url"
+                | "allow_writes"
+        ) {
+            return Err(unsupported());
+        }
+        let allow_writes = if field == "allow_writes" {
+            Some(parse_bool(value)?)
+        } else {
+            None
+        };
+
+        let profile = self.profiles.entry(name.to_string()).or_default();
+        match field {
+            "client_id" => profile.client_id = Some(value.to_string()),
+            "client_secret" => profile.client_secret = Some(value.to_string()),
+            "base_url" => profile.base_url = Some(value.to_string()),
+            "authorize_url" => profile.authorize_url = Some(value.to_string()),
+            "token_url" => profile.token_url = Some(value.to_string()),
+            _ => profile.allow_writes = allow_writes,
+        }
+        Ok(())
+    }
+
     /// JSON value with secret redaction.
     pub fn as_redacted_json(&self) -> serde_json::Value {
         let mut value = serde_json::to_value(self).unwrap_or_else(|_| serde_json::json!({}));
@@ -207,10 +374,221 @@ impl AppConfig {
         {
             *secret = serde_json::Value::String("[REDACTED]".to_string());
         }
+        if let Some(profiles) = value
+            .get_mut("profile")
+            .and_then(serde_json::Value::as_object_mut)
+        {
+            for secret in profiles
+                .values_mut()
+                .filter_map(|profile| profile.get_mut("client_secret"))
+            {
+                *secret = serde_json::Value::String("[REDACTED]".to_string());
+            }
+        }
         value
     }
 }
 
+/// Global flag values that take precedence over env and config file.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FlagOverrides<'a> {
+    /// `--client-id`.
+    pub client_id: Option<&'a str>,
+    /// `--client-secret`.
+    pub client_secret: Option<&'a str>,
+    /// `--limit`.
+    pub limit: Option<usize>,
+    /// `--profile`.
+    pub profile: Option<&'a str>,
+}
+
+/// One effective setting and where its value came from.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedSetting {
+    /// Dotted config key.
+    pub key: &'static str,
+    /// Effective value (secrets redacted).
+    pub value: serde_json::Value,
+    /// `flag`, `env`, `profile`, `file`, `default` or `unset`.
+    pub source: &'static str,
+}
+
+impl ResolvedSetting {
+    fn new(key: &'static str, value: impl Serialize, source: &'static str) -> Self {
+        Self {
+            key,
+            value: serde_json::to_value(value).unwrap_or(serde_json::Value::Null),
+            source,
+        }
+    }
+}
+
+impl AppConfig {
+    /// Resolves every runtime setting with its provenance
+    /// (flag > env > profile > file > default).
+    pub fn resolved_settings(&self, flags: FlagOverrides<'_>) -> Result<Vec<ResolvedSetting>> {
+        let defaults = SdkConfig::default();
+        let effective = self.sdk_config()?;
+        let env = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        let file_or_default = |set: bool| if set { "file" } else { "default" };
+        let profile = self.active_profile_config().cloned().unwrap_or_default();
+        let file_source = |from_profile: bool| if from_profile { "profile" } else { "file" };
+        let oauth_source = |from_profile: bool, from_file: bool| {
+            if from_profile || profile.base_url.is_some() {
+                "profile"
+            } else {
+                file_or_default(from_file)
+            }
+        };
+
+        let credential = |key,
+                          flag: Option<&str>,
+                          env_name,
+                          file: &Option<String>,
+                          from_profile: bool,
+                          secret| {
+            let file = file.as_deref().filter(|value| !value.trim().is_empty());
+            let (value, source) = match (flag, env(env_name), file) {
+                (Some(value), _, _) => (Some(value.to_string()), "flag"),
+                (None, Some(value), _) => (Some(value), "env"),
+                (None, None, Some(value)) => (Some(value.to_string()), file_source(from_profile)),
+                (None, None, None) => (None, "unset"),
+            };
+            let value = if secret {
+                value.map(|_| "[REDACTED]".to_string())
+            } else {
+                value
+            };
+            ResolvedSetting::new(key, value, source)
+        };
+
+        let limit = flags.limit.or(self.defaults.limit).unwrap_or(100);
+        let limit_source = if flags.limit.is_some() {
+            "flag"
+        } else {
+            file_or_default(limit != 100)
+        };
+
+        let profile_token_file = self.profile_token_file()?;
+        let token_file = profile_token_file
+            .clone()
+            .map(Ok)
+            .unwrap_or_else(cho_sdk::home::token_path)
+            .map(|path| path.display().to_string())
+            .ok();
+
+        Ok(vec![
+            ResolvedSetting::new(
+                "profile",
+                &self.active_profile,
+                match (flags.profile, env("CHO_PROFILE")) {
+                    (Some(_), _) => "flag",
+                    (None, Some(_)) => "env",
+                    (None, None) => "unset",
+                },
+            ),
+            credential(
+                "auth.client_id",
+                flags.client_id,
+                "CHO_CLIENT_ID",
+                &self.auth.client_id,
+                profile.client_id.is_some(),
+                false,
+            ),
+            credential(
+                "auth.client_secret",
+                flags.client_secret,
+                "CHO_CLIENT_SECRET",
+                &self.auth.client_secret,
+                profile.client_secret.is_some(),
+                true,
+            ),
+            ResolvedSetting::new("defaults.limit", limit, limit_source),
+            ResolvedSetting::new(
+                "sdk.base_url",
+                &effective.base_url,
+                if env("CHO_BASE_URL").is_some() {
+                    "env"
+                } else if profile.base_url.is_some() {
+                    "profile"
+                } else {
+                    file_or_default(self.sdk.base_url.is_some())
+                },
+            ),
+            ResolvedSetting::new(
+                "sdk.authorize_url",
+                &effective.authorize_url,
+                oauth_source(
+                    profile.authorize_url.is_some(),
+                    self.sdk.authorize_url.is_some(),
+                ),
+            ),
+            ResolvedSetting::new(
+                "sdk.token_url",
+                &effective.token_url,
+                oauth_source(profile.token_url.is_some(), self.sdk.token_url.is_some()),
+            ),
+            ResolvedSetting::new(
+                "sdk.timeout_secs",
+                effective.timeout.as_secs(),
+                file_or_default(self.sdk.timeout_secs.is_some()),
+            ),
+            ResolvedSetting::new(
+                "sdk.max_retries",
+                effective.max_retries,
+                file_or_default(self.sdk.max_retries.is_some()),
+            ),
+            ResolvedSetting::new(
+                "sdk.max_retry_duration_secs",
+                effective
+                    .max_retry_duration
+                    .map(|duration| duration.as_secs()),
+                file_or_default(self.sdk.max_retry_duration_secs.is_some()),
+            ),
+            ResolvedSetting::new(
+                "safety.allow_writes",
+                effective.allow_writes,
+                if profile.allow_writes.is_some() {
+                    "profile"
+                } else {
+                    file_or_default(effective.allow_writes != defaults.allow_writes)
+                },
+            ),
+            ResolvedSetting::new(
+                "token_file",
+                token_file,
+                if env("CHO_TOKEN_FILE").is_some() {
+                    "env"
+                } else if profile_token_file.is_some() {
+                    "profile"
+                } else {
+                    "default"
+                },
+            ),
+        ])
+    }
+}
+
+/// True for config keys whose values must never be echoed or logged.
+pub fn is_secret_config_key(key: &str) -> bool {
+    key == "auth.client_secret" || (key.starts_with("profile.") && key.ends_with(".client_secret"))
+}
+
+/// Rejects profile names that are empty or unsafe in a token file name.
+fn validate_profile_name(name: &str) -> Result<()> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        Ok(())
+    } else {
+        Err(ChoSdkError::Config {
+            message: format!("Invalid profile name '{name}' (use letters, digits, '-' or '_')"),
+        })
+    }
+}
+
 /// Parses key-value `query` args where each entry is `key=value`.
 pub fn parse_query_pairs(entries: &[String]) -> Result<Vec<(String, String)>> {
     let mut out = Vec::new();
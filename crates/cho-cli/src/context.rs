@@ -8,8 +8,9 @@ use cho_sdk::models::{ListResult, Pagination};
 use serde::Serialize;
 
 use crate::audit::AuditLogger;
-use crate::envelope::{self, OutputFormat};
+use crate::envelope::{self, Meta, OutputFormat};
 use crate::output::json::{JsonOptions, apply_json_options};
+use crate::output::toon::tabulate_line_items;
 
 /// Shared command execution context.
 pub struct CliContext {
@@ -18,8 +19,10 @@ pub struct CliContext {
     json_options: JsonOptions,
     limit: usize,
     explicit_limit: bool,
+    page_size: u32,
     all: bool,
-    allow_writes: bool,
+    count_only: bool,
+    dry_run: bool,
     audit: AuditLogger,
 }
 
@@ -31,7 +34,6 @@ impl CliContext {
         json_options: JsonOptions,
         limit: usize,
         all: bool,
-        allow_writes: bool,
         audit: AuditLogger,
     ) -> Self {
         Self {
@@ -40,8 +42,10 @@ impl CliContext {
             json_options,
             limit,
             explicit_limit: false,
+            page_size: 100,
             all,
-            allow_writes,
+            count_only: false,
+            dry_run: false,
             audit,
         }
     }
@@ -52,24 +56,77 @@ impl CliContext {
         self
     }
 
+    /// Sets the per-request page size, clamped to FreeAgent's `1..=100`.
+    pub fn with_page_size(mut self, page_size: Option<u32>) -> Self {
+        if let Some(page_size) = page_size {
+            self.page_size = page_size.clamp(1, 100);
+        }
+        self
+    }
+
+    /// Marks whether list commands should emit only the total count.
+    pub fn with_count_only(mut self, count_only: bool) -> Self {
+        self.count_only = count_only;
+        self
+    }
+
+    /// Marks whether write commands should preview requests instead of sending them.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Returns true when `--dry-run` was requested.
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
     /// Returns client.
     pub fn client(&self) -> &FreeAgentClient {
         &self.client
     }
 
-    /// Returns list pagination settings.
+    /// Returns list pagination settings from `--all`, `--limit`, and `--page-size`.
     pub fn pagination(&self) -> Pagination {
         if self.all {
-            Pagination::all()
+            Pagination {
+                per_page: self.page_size,
+                ..Pagination::all()
+            }
         } else {
             Pagination {
-                per_page: 100,
+                per_page: self.page_size,
                 limit: self.limit.min(10_000),
                 all: false,
             }
         }
     }
 
+    /// Returns pagination for a list fetch that feeds [`Self::emit_list`].
+    ///
+    /// A command-level `--per-page` overrides the global page size; under
+    /// `--count-only` one item is fetched so the count comes from `X-Total-Count`.
+    pub fn list_pagination(&self, per_page: Option<u32>) -> Pagination {
+        if self.count_only {
+            return Pagination {
+                per_page: 1,
+                limit: 1,
+                all: false,
+            };
+        }
+
+        let mut pagination = self.pagination();
+        if let Some(per_page) = per_page {
+            pagination.per_page = per_page.clamp(1, 100);
+        }
+        pagination
+    }
+
+    /// Returns the per-request page size.
+    pub fn page_size(&self) -> u32 {
+        self.page_size
+    }
+
     /// Returns the configured item limit regardless of `--all`.
     pub fn limit(&self) -> usize {
         self.limit.min(10_000)
@@ -89,17 +146,17 @@ impl CliContext {
         self.all
     }
 
-    /// Fails when writes are disabled.
+    /// Fails when writes are disabled, using the SDK client's own write gate.
     pub fn require_writes_allowed(&self) -> Result<()> {
-        if self.allow_writes {
-            Ok(())
-        } else {
-            Err(ChoSdkError::WriteNotAllowed {
-                message:
-                    "Write operations are blocked. Set [safety] allow_writes = true in config.toml"
-                        .to_string(),
-            })
+        if self.dry_run {
+            // Commands that support previews return before reaching the gate.
+            return Err(ChoSdkError::Config {
+                message: "invalid option --dry-run: this command does not support request previews"
+                    .to_string(),
+            });
         }
+
+        self.client.ensure_writes_allowed()
     }
 
     /// Logs structured command input payload.
@@ -109,10 +166,46 @@ impl CliContext {
 
     /// Emits one-item success output.
     pub fn emit_success<T: Serialize>(&self, tool: &str, data: &T, start: Instant) -> Result<()> {
-        let value = serialize_transform(data, &self.json_options)?;
+        if self.count_only {
+            // List commands emit through `emit_list`; anything else cannot count.
+            return Err(count_only_unsupported());
+        }
+
+        let mut value = serialize_transform(data, &self.json_options)?;
+        if self.output_format == OutputFormat::Toon {
+            value = tabulate_line_items(value);
+        }
 
         let output =
-            envelope::emit_success(tool, value, start, None, None, None, self.output_format);
+            envelope::emit_success_with_meta(value, self.meta(tool, start), self.output_format);
+
+        self.audit.log_command_output(tool, &output)?;
+        envelope::write_stdout(&output);
+        Ok(())
+    }
+
+    /// Emits the request a write command would send without sending it.
+    pub fn emit_dry_run(
+        &self,
+        tool: &str,
+        method: &str,
+        path: &str,
+        body: Option<&serde_json::Value>,
+        start: Instant,
+    ) -> Result<()> {
+        let request = serde_json::json!({
+            "method": method,
+            "path": path,
+            "body": body,
+        });
+        self.log_input(tool, &request)?;
+        let value = serialize_transform(&request, &self.json_options)?;
+
+        let meta = Meta {
+            dry_run: Some(true),
+            ..Meta::new(tool, start)
+        };
+        let output = envelope::emit_success_with_meta(value, meta, self.output_format);
 
         self.audit.log_command_output(tool, &output)?;
         envelope::write_stdout(&output);
@@ -121,15 +214,43 @@ impl CliContext {
 
     /// Emits list success output.
     pub fn emit_list(&self, tool: &str, result: &ListResult, start: Instant) -> Result<()> {
+        if self.count_only {
+            return self.emit_count(tool, result, start);
+        }
+
         let value = serialize_transform(&result.items, &self.json_options)?;
 
-        let output = envelope::emit_success(
-            tool,
-            value,
-            start,
-            Some(result.items.len()),
-            result.total,
-            Some(result.has_more),
+        let meta = Meta {
+            count: Some(result.items.len()),
+            total: result.total,
+            has_more: Some(result.has_more),
+            ..self.meta(tool, start)
+        };
+        let output = envelope::emit_success_with_meta(value, meta, self.output_format);
+
+        self.audit.log_command_output(tool, &output)?;
+        envelope::write_stdout(&output);
+        Ok(())
+    }
+
+    fn emit_count(&self, tool: &str, result: &ListResult, start: Instant) -> Result<()> {
+        let count = match result.total {
+            Some(total) => total,
+            None if !result.has_more => result.items.len(),
+            None => {
+                return Err(ChoSdkError::Config {
+                    message: "FreeAgent did not report a total for this list; rerun without --count-only and count the items".to_string(),
+                });
+            }
+        };
+
+        let meta = Meta {
+            total: Some(count),
+            ..self.meta(tool, start)
+        };
+        let output = envelope::emit_success_with_meta(
+            serde_json::json!({ "count": count }),
+            meta,
             self.output_format,
         );
 
@@ -137,6 +258,21 @@ impl CliContext {
         envelope::write_stdout(&output);
         Ok(())
     }
+
+    fn meta(&self, tool: &str, start: Instant) -> Meta {
+        Meta {
+            rate_limit: self.client.rate_limit_snapshot().map(Into::into),
+            ..Meta::new(tool, start)
+        }
+    }
+}
+
+/// Error for `--count-only` on a command without a paginated list result.
+pub fn count_only_unsupported() -> ChoSdkError {
+    ChoSdkError::Config {
+        message: "invalid option --count-only: this command does not return a paginated list"
+            .to_string(),
+    }
 }
 
 fn serialize_transform<T: Serialize + ?Sized>(
@@ -2,6 +2,7 @@
 
 use std::time::Instant;
 
+use cho_sdk::models::RateLimitSnapshot;
 use serde::Serialize;
 
 /// Structured stdout format.
@@ -11,6 +12,8 @@ pub enum OutputFormat {
     Json,
     /// Toon envelope.
     Toon,
+    /// Compact JSON `data` without the envelope; errors go to stderr.
+    BareJson,
 }
 
 /// Success envelope.
@@ -66,6 +69,59 @@ pub struct Meta {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "hasMore")]
     pub has_more: Option<bool>,
+    /// Set when the payload is a write preview that was not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dryRun")]
+    pub dry_run: Option<bool>,
+    /// Optional API quota reported by the latest response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "rateLimit")]
+    pub rate_limit: Option<RateLimitMeta>,
+}
+
+/// API quota reported by the latest response.
+#[derive(Serialize)]
+pub struct RateLimitMeta {
+    /// Request allowance for the current window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    /// Requests remaining in the current window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining: Option<u64>,
+    /// Seconds until the window resets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "resetSecs")]
+    pub reset_secs: Option<u64>,
+    /// Seconds the API asked callers to wait after a 429.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "retryAfter")]
+    pub retry_after: Option<u64>,
+}
+
+impl From<RateLimitSnapshot> for RateLimitMeta {
+    fn from(snapshot: RateLimitSnapshot) -> Self {
+        Self {
+            limit: snapshot.limit,
+            remaining: snapshot.remaining,
+            reset_secs: snapshot.reset_secs,
+            retry_after: snapshot.retry_after,
+        }
+    }
+}
+
+impl Meta {
+    /// Creates metadata carrying only tool name and elapsed time.
+    pub fn new(tool: &str, start: Instant) -> Self {
+        Self {
+            tool: tool.to_string(),
+            elapsed: start.elapsed().as_millis() as u64,
+            count: None,
+            total: None,
+            has_more: None,
+            dry_run: None,
+            rate_limit: None,
+        }
+    }
 }
 
 /// Renders a success envelope.
@@ -78,19 +134,31 @@ pub fn emit_success<T: Serialize>(
     has_more: Option<bool>,
     format: OutputFormat,
 ) -> String {
+    let meta = Meta {
+        count,
+        total,
+        has_more,
+        ..Meta::new(tool, start)
+    };
+
+    emit_success_with_meta(data, meta, format)
+}
+
+/// Renders a success envelope with caller-prepared metadata.
+pub fn emit_success_with_meta<T: Serialize>(data: T, meta: Meta, format: OutputFormat) -> String {
+    let tool = meta.tool.clone();
+    if format == OutputFormat::BareJson {
+        return serde_json::to_string(&data)
+            .unwrap_or_else(|err| fallback_error(&tool, &err.to_string()));
+    }
+
     let envelope = SuccessEnvelope {
         ok: true,
         data,
-        meta: Meta {
-            tool: tool.to_string(),
-            elapsed: start.elapsed().as_millis() as u64,
-            count,
-            total,
-            has_more,
-        },
+        meta,
     };
 
-    render(&envelope, format).unwrap_or_else(|err| fallback_error(tool, &err))
+    render(&envelope, format).unwrap_or_else(|err| fallback_error(&tool, &err))
 }
 
 /// Renders an error envelope.
@@ -111,13 +179,7 @@ pub fn emit_error(
             hint,
             details,
         },
-        meta: Meta {
-            tool: tool.to_string(),
-            elapsed: start.elapsed().as_millis() as u64,
-            count: None,
-            total: None,
-            has_more: None,
-        },
+        meta: Meta::new(tool, start),
     };
 
     render(&envelope, format).unwrap_or_else(|err| fallback_error(tool, &err))
@@ -128,9 +190,20 @@ pub fn write_stdout(output: &str) {
     println!("{output}");
 }
 
+/// Writes an error envelope, keeping stdout data-only in bare JSON mode.
+pub fn write_error(output: &str, format: OutputFormat) {
+    if format == OutputFormat::BareJson {
+        eprintln!("{output}");
+    } else {
+        write_stdout(output);
+    }
+}
+
 fn render<T: Serialize>(value: &T, format: OutputFormat) -> Result<String, String> {
     match format {
-        OutputFormat::Json => serde_json::to_string(value).map_err(|err| err.to_string()),
+        OutputFormat::Json | OutputFormat::BareJson => {
+            serde_json::to_string(value).map_err(|err| err.to_string())
+        }
         OutputFormat::Toon => toon_format::encode_default(value).map_err(|err| err.to_string()),
     }
 }
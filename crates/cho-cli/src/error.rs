@@ -2,10 +2,13 @@
 
 use std::time::Instant;
 
-use cho_sdk::error::ChoSdkError;
+use cho_sdk::error::{ChoSdkError, api_error_messages};
 
 use crate::envelope::{self, OutputFormat};
 
+/// Longest raw API body echoed into an error message when no error shape is recognised.
+const MAX_RAW_ERROR_BODY_CHARS: usize = 500;
+
 /// Stable CLI error codes.
 #[derive(Debug, Clone, Copy)]
 pub enum ErrorCode {
@@ -15,6 +18,8 @@ pub enum ErrorCode {
     TokenExpired,
     /// Rate limited.
     RateLimited,
+    /// Access denied for the current user or app.
+    Forbidden,
     /// Resource not found.
     NotFound,
     /// Validation/business error.
@@ -42,6 +47,7 @@ impl ErrorCode {
             Self::AuthRequired => "auth_required",
             Self::TokenExpired => "token_expired",
             Self::RateLimited => "rate_limited",
+            Self::Forbidden => "forbidden",
             Self::NotFound => "not_found",
             Self::ValidationError => "validation_error",
             Self::ApiError => "api_error",
@@ -60,6 +66,9 @@ impl ErrorCode {
             Self::AuthRequired => "Run 'cho auth login' to authenticate",
             Self::TokenExpired => "Run 'cho auth login' to re-authenticate",
             Self::RateLimited => "Wait and retry using error.details.retryAfter when provided",
+            Self::Forbidden => {
+                "Run 'cho auth login' as a FreeAgent user whose access level covers this resource"
+            }
             Self::NotFound => "Verify the resource identifier/path",
             Self::ValidationError => "Check request payload fields and values",
             Self::ApiError => "Retry once and inspect FreeAgent API response details",
@@ -80,6 +89,7 @@ impl ErrorCode {
             self,
             Self::AuthRequired
                 | Self::TokenExpired
+                | Self::Forbidden
                 | Self::WriteNotAllowed
                 | Self::AuditLogUnavailable
         ) {
@@ -96,10 +106,12 @@ impl From<&ChoSdkError> for ErrorCode {
             ChoSdkError::AuthRequired { .. } => Self::AuthRequired,
             ChoSdkError::TokenExpired { .. } => Self::TokenExpired,
             ChoSdkError::RateLimited { .. } => Self::RateLimited,
+            ChoSdkError::Forbidden { .. } => Self::Forbidden,
             ChoSdkError::NotFound { .. } => Self::NotFound,
             ChoSdkError::ApiError { status, .. } if *status == 400 || *status == 422 => {
                 Self::ValidationError
             }
+            ChoSdkError::Validation { .. } => Self::ValidationError,
             ChoSdkError::ApiError { .. } => Self::ApiError,
             ChoSdkError::Network(_) => Self::NetworkError,
             ChoSdkError::Parse { .. } => Self::ParseError,
@@ -121,17 +133,35 @@ pub fn format_error(
     start: Instant,
 ) -> String {
     let code = ErrorCode::from(err);
+    let mut message = err.to_string();
     let details = match err {
         ChoSdkError::RateLimited { retry_after } => {
             Some(serde_json::json!({ "retryAfter": retry_after }))
         }
+        ChoSdkError::Validation { errors, .. } => Some(serde_json::json!({ "errors": errors })),
+        ChoSdkError::ApiError {
+            status,
+            message: body,
+        } => {
+            let errors = api_error_messages(body);
+            if errors.is_empty() {
+                message = format!(
+                    "api error {status}: {}",
+                    truncate_chars(body.trim(), MAX_RAW_ERROR_BODY_CHARS)
+                );
+                None
+            } else {
+                message = format!("api error {status}: {}", errors.join("; "));
+                Some(serde_json::json!({ "status": status, "errors": errors }))
+            }
+        }
         _ => None,
     };
 
     envelope::emit_error(
         tool,
         code.as_str(),
-        err.to_string(),
+        message,
         code.hint().to_string(),
         details,
         start,
@@ -148,3 +178,61 @@ fn looks_like_usage_error(message: &str) -> bool {
     let lower = message.to_ascii_lowercase();
     lower.starts_with("invalid ") || lower.contains("usage") || lower.contains("unknown option")
 }
+
+/// Truncates on a char boundary, appending an ellipsis when text was cut.
+fn truncate_chars(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((cut, _)) => format!("{}…", &text[..cut]),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+
+    #[test]
+    fn truncate_chars_respects_utf8_boundaries() {
+        assert_eq!(truncate_chars("£££££", 3), "£££…");
+        assert_eq!(truncate_chars("short", 10), "short");
+    }
+
+    #[test]
+    fn format_error_summarises_recognised_api_errors() {
+        let err = ChoSdkError::ApiError {
+            status: 422,
+            message: r#"{"errors":{"error":{"message":"Reference has already been taken"}}}"#
+                .to_string(),
+        };
+        let output = format_error(&err, OutputFormat::Json, "invoices.create", Instant::now());
+        let json: Value = serde_json::from_str(&output).expect("json envelope");
+
+        assert_eq!(
+            json["error"]["message"],
+            "api error 422: Reference has already been taken"
+        );
+        assert_eq!(json["error"]["details"]["status"], 422);
+    }
+
+    #[test]
+    fn format_error_lists_local_validation_failures() {
+        let err = ChoSdkError::Validation {
+            resource: "invoice".to_string(),
+            errors: vec![
+                "contact is required".to_string(),
+                "dated_on is required".to_string(),
+            ],
+        };
+        let output = format_error(&err, OutputFormat::Json, "invoices.create", Instant::now());
+        let json: Value = serde_json::from_str(&output).expect("json envelope");
+
+        assert_eq!(json["error"]["code"], "validation_error");
+        assert_eq!(
+            json["error"]["details"]["errors"],
+            serde_json::json!(["contact is required", "dated_on is required"])
+        );
+        assert_eq!(exit_code(&err), 1);
+    }
+}
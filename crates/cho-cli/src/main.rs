@@ -20,7 +20,7 @@ use tracing_subscriber::EnvFilter;
 use cho_sdk::auth::AuthManager;
 use cho_sdk::client::{FreeAgentClient, HttpObserver};
 
-use crate::audit::{AuditLogger, audit_unavailable_error};
+use crate::audit::{AuditLogger, ProgressEcho, RequestEcho, audit_unavailable_error};
 use crate::commands::auth::AuthCommands;
 use crate::commands::company::CompanyCommands;
 use crate::commands::config::ConfigCommands;
@@ -51,6 +51,10 @@ struct Cli {
     #[arg(long, global = true)]
     toon: bool,
 
+    /// Emit only the JSON `data` payload; errors go to stderr.
+    #[arg(long, global = true, conflicts_with = "toon")]
+    no_envelope: bool,
+
     /// Convert decimal-like numbers to strings in JSON output.
     #[arg(long, global = true)]
     precise: bool,
@@ -63,6 +67,26 @@ struct Cli {
     #[arg(long, global = true)]
     all: bool,
 
+    /// Items requested per API page (`1..=100`); independent of `--limit`.
+    #[arg(long, global = true)]
+    page_size: Option<u32>,
+
+    /// Return only the total item count for list commands (fetches one item).
+    #[arg(long, global = true, conflicts_with = "all")]
+    count_only: bool,
+
+    /// Print list pagination progress to stderr after each page.
+    #[arg(long, global = true)]
+    progress: bool,
+
+    /// Print the request body for write commands without sending it.
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Config profile to use (`[profile.<name>]`; falls back to `CHO_PROFILE`).
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
     /// Override OAuth client id.
     #[arg(long, global = true)]
     client_id: Option<String>,
@@ -71,6 +95,10 @@ struct Cli {
     #[arg(long, global = true)]
     client_secret: Option<String>,
 
+    /// Print each HTTP request line and headers to stderr (credentials redacted).
+    #[arg(long, global = true)]
+    show_request: bool,
+
     /// Enable verbose tracing logs.
     #[arg(long, global = true)]
     verbose: bool,
@@ -404,6 +432,14 @@ async fn main() {
         return;
     }
 
+    if cli.count_only && !tool_name.ends_with(".list") {
+        let err = context::count_only_unsupported();
+        emit_runtime_error(&err, output_format, &tool_name, start, Some(&audit));
+        let code = error::exit_code(&err);
+        log_command_end_or_exit(&audit, &tool_name, code, start, output_format);
+        std::process::exit(code);
+    }
+
     // Early commands that do not require API client.
     match &cli.command {
         Commands::Start => match commands::start::run() {
@@ -435,20 +471,29 @@ async fn main() {
                 }
             }
         }
-        Commands::Health => match commands::health::run(output_format, start, &audit).await {
-            Ok(exit_code) => {
-                log_command_end_or_exit(&audit, &tool_name, exit_code, start, output_format);
-                std::process::exit(exit_code);
-            }
-            Err(err) => {
-                emit_runtime_error(&err, output_format, &tool_name, start, Some(&audit));
-                let code = error::exit_code(&err);
-                log_command_end_or_exit(&audit, &tool_name, code, start, output_format);
-                std::process::exit(code);
+        Commands::Health => {
+            match commands::health::run(output_format, start, &audit, cli.profile.as_deref()).await
+            {
+                Ok(exit_code) => {
+                    log_command_end_or_exit(&audit, &tool_name, exit_code, start, output_format);
+                    std::process::exit(exit_code);
+                }
+                Err(err) => {
+                    emit_runtime_error(&err, output_format, &tool_name, start, Some(&audit));
+                    let code = error::exit_code(&err);
+                    log_command_end_or_exit(&audit, &tool_name, code, start, output_format);
+                    std::process::exit(code);
+                }
             }
-        },
+        }
         Commands::Config { command } => {
-            match commands::config::run(command, output_format, start, &audit) {
+            let flags = commands::utils::FlagOverrides {
+                client_id: cli.client_id.as_deref(),
+                client_secret: cli.client_secret.as_deref(),
+                limit: cli.limit,
+                profile: cli.profile.as_deref(),
+            };
+            match commands::config::run(command, output_format, start, &audit, flags) {
                 Ok(()) => {
                     log_command_end_or_exit(&audit, &tool_name, 0, start, output_format);
                     return;
@@ -470,6 +515,16 @@ async fn main() {
         _ => {}
     }
 
+    let config = match config.with_profile(cli.profile.as_deref()) {
+        Ok(config) => config,
+        Err(err) => {
+            emit_runtime_error(&err, output_format, &tool_name, start, Some(&audit));
+            let code = error::exit_code(&err);
+            log_command_end_or_exit(&audit, &tool_name, code, start, output_format);
+            std::process::exit(code);
+        }
+    };
+
     let client_id = match cli.client_id.or_else(|| config.resolve_client_id()) {
         Some(value) => value,
         None => {
@@ -497,8 +552,15 @@ async fn main() {
         }
     };
 
-    let sdk_config = config.sdk_config();
-    let allow_writes = sdk_config.allow_writes;
+    let sdk_config = match config.sdk_config() {
+        Ok(sdk_config) => sdk_config,
+        Err(err) => {
+            emit_runtime_error(&err, output_format, &tool_name, start, Some(&audit));
+            let code = error::exit_code(&err);
+            log_command_end_or_exit(&audit, &tool_name, code, start, output_format);
+            std::process::exit(code);
+        }
+    };
 
     let auth = match AuthManager::new(
         client_id,
@@ -521,7 +583,14 @@ async fn main() {
         std::process::exit(code);
     }
 
-    let observer: Arc<dyn HttpObserver> = Arc::new(audit.clone());
+    let mut observer: Arc<dyn HttpObserver> = if cli.show_request {
+        Arc::new(RequestEcho::new(audit.clone()))
+    } else {
+        Arc::new(audit.clone())
+    };
+    if cli.progress {
+        observer = Arc::new(ProgressEcho::new(observer));
+    }
     let client = match FreeAgentClient::builder()
         .config(sdk_config)
         .auth_manager(auth)
@@ -545,10 +614,12 @@ async fn main() {
         },
         limit,
         cli.all,
-        allow_writes,
         audit.clone(),
     )
-    .with_explicit_limit(explicit_limit);
+    .with_explicit_limit(explicit_limit)
+    .with_page_size(cli.page_size)
+    .with_count_only(cli.count_only)
+    .with_dry_run(cli.dry_run);
 
     let (tool, result) = dispatch_command(&cli.command, &context, start).await;
 
@@ -764,6 +835,8 @@ async fn dispatch_command(
 fn resolve_output_format(cli: &Cli) -> OutputFormat {
     if cli.toon {
         OutputFormat::Toon
+    } else if cli.no_envelope {
+        OutputFormat::BareJson
     } else {
         OutputFormat::Json
     }
@@ -791,11 +864,16 @@ fn positional_tokens(args: &[String]) -> Vec<String> {
     let mut index = 0;
     while index < args.len() {
         let arg = &args[index];
-        if matches!(arg.as_str(), "--limit" | "--client-id" | "--client-secret") {
+        if matches!(
+            arg.as_str(),
+            "--limit" | "--page-size" | "--profile" | "--client-id" | "--client-secret"
+        ) {
             index += 2;
             continue;
         }
         if arg.starts_with("--limit=")
+            || arg.starts_with("--page-size=")
+            || arg.starts_with("--profile=")
             || arg.starts_with("--client-id=")
             || arg.starts_with("--client-secret=")
         {
@@ -845,7 +923,7 @@ fn emit_runtime_error(
         let wrapped = audit_unavailable_error(err);
         emit_bootstrap_error(&wrapped, output_format, "bootstrap.audit", start, 2, None);
     }
-    envelope::write_stdout(&output);
+    envelope::write_error(&output, output_format);
 }
 
 fn emit_bootstrap_error(
@@ -863,7 +941,7 @@ fn emit_bootstrap_error(
         let wrapped = audit_unavailable_error(err);
         emit_bootstrap_error(&wrapped, output_format, "bootstrap.audit", start, 2, None);
     }
-    envelope::write_stdout(&output);
+    envelope::write_error(&output, output_format);
     std::process::exit(exit_code);
 }
 
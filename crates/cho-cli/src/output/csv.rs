@@ -0,0 +1,50 @@
+//! CSV output helpers.
+
+/// Renders rows as RFC 4180 CSV with CRLF line endings.
+pub fn to_csv(rows: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    for row in rows {
+        let line = row
+            .iter()
+            .map(|field| escape_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+        out.push_str(&line);
+        out.push_str("\r\n");
+    }
+    out
+}
+
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_csv_quotes_fields_with_separators_quotes_and_newlines() {
+        let rows = vec![
+            vec![
+                "level".to_string(),
+                "label".to_string(),
+                "value".to_string(),
+            ],
+            vec![
+                "1".to_string(),
+                "Loans, \"long\"".to_string(),
+                "10".to_string(),
+            ],
+            vec!["1".to_string(), "two\nlines".to_string(), String::new()],
+        ];
+        assert_eq!(
+            to_csv(&rows),
+            "level,label,value\r\n1,\"Loans, \"\"long\"\"\",10\r\n1,\"two\nlines\",\r\n"
+        );
+    }
+}
@@ -1,3 +1,5 @@
 //! Output format helpers.
 
+pub mod csv;
 pub mod json;
+pub mod toon;
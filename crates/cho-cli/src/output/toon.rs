@@ -0,0 +1,112 @@
+//! Toon output transforms.
+
+use serde_json::{Map, Value};
+
+/// Document line-item arrays rendered as Toon tables.
+const LINE_ITEM_KEYS: &[&str] = &[
+    "invoice_items",
+    "estimate_items",
+    "credit_note_items",
+    "bill_items",
+];
+
+/// Pads line-item rows to one key set so Toon renders them as a table.
+///
+/// Toon only tabulates arrays of objects that share keys and hold scalar
+/// values. FreeAgent omits unset item fields, so missing keys are filled
+/// with `null`; arrays containing nested values are left as lists.
+pub fn tabulate_line_items(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::Array(items) if LINE_ITEM_KEYS.contains(&key.as_str()) => {
+                            Value::Array(pad_rows(items))
+                        }
+                        other => tabulate_line_items(other),
+                    };
+                    (key, value)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(tabulate_line_items).collect()),
+        other => other,
+    }
+}
+
+fn pad_rows(items: Vec<Value>) -> Vec<Value> {
+    let tabular = items.iter().all(|item| {
+        item.as_object().is_some_and(|row| {
+            row.values()
+                .all(|value| !value.is_object() && !value.is_array())
+        })
+    });
+    if !tabular {
+        return items;
+    }
+
+    let mut columns = Map::new();
+    for item in &items {
+        if let Some(row) = item.as_object() {
+            for key in row.keys() {
+                columns.insert(key.clone(), Value::Null);
+            }
+        }
+    }
+
+    items
+        .into_iter()
+        .map(|item| match item {
+            Value::Object(row) => {
+                let mut padded = columns.clone();
+                padded.extend(row);
+                Value::Object(padded)
+            }
+            other => other,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tabulate_line_items_pads_rows_so_toon_emits_a_table() {
+        let value = serde_json::json!({
+            "invoice": {
+                "reference": "INV-001",
+                "invoice_items": [
+                    { "description": "Design", "price": "500.0", "quantity": "2.0" },
+                    { "description": "Hosting", "price": "20.0" }
+                ]
+            }
+        });
+
+        let padded = tabulate_line_items(value);
+        assert_eq!(
+            padded["invoice"]["invoice_items"][1]["quantity"],
+            Value::Null
+        );
+
+        let rendered = toon_format::encode_default(&padded).expect("toon renders");
+        assert!(
+            rendered.contains("invoice_items[2]{description,price,quantity}:"),
+            "{rendered}"
+        );
+    }
+
+    #[test]
+    fn tabulate_line_items_leaves_nested_rows_and_other_arrays_alone() {
+        let value = serde_json::json!({
+            "estimate_items": [
+                { "description": "A", "category": { "url": "c" } },
+                { "description": "B" }
+            ],
+            "contacts": [{ "name": "A" }, { "email": "b@example.com" }]
+        });
+
+        assert_eq!(tabulate_line_items(value.clone()), value);
+    }
+}
@@ -66,6 +66,11 @@ pub const GLOBAL_FLAGS: &[GlobalFlagMeta] = &[
         description: "Emit Toon instead of the default JSON envelope",
         default: "false",
     },
+    GlobalFlagMeta {
+        name: "--no-envelope",
+        description: "Emit only the JSON data payload; errors go to stderr",
+        default: "false",
+    },
     GlobalFlagMeta {
         name: "--limit",
         description: "Maximum items to return for list commands",
@@ -76,6 +81,36 @@ pub const GLOBAL_FLAGS: &[GlobalFlagMeta] = &[
         description: "Fetch all available pages",
         default: "false",
     },
+    GlobalFlagMeta {
+        name: "--page-size",
+        description: "Items per API request (1-100); --limit caps total items, --all ignores the cap",
+        default: "100",
+    },
+    GlobalFlagMeta {
+        name: "--count-only",
+        description: "Return only the total item count for list commands (fetches one item)",
+        default: "false",
+    },
+    GlobalFlagMeta {
+        name: "--progress",
+        description: "Print list pagination progress to stderr after each page",
+        default: "false",
+    },
+    GlobalFlagMeta {
+        name: "--dry-run",
+        description: "Print write request bodies without sending them",
+        default: "false",
+    },
+    GlobalFlagMeta {
+        name: "--profile",
+        description: "Config profile to use ([profile.<name>]); falls back to CHO_PROFILE",
+        default: "none",
+    },
+    GlobalFlagMeta {
+        name: "--show-request",
+        description: "Print each HTTP request line and headers to stderr with credentials redacted",
+        default: "false",
+    },
     GlobalFlagMeta {
         name: "--verbose",
         description: "Enable tracing logs to stderr",
@@ -130,7 +165,7 @@ pub fn tool_catalog() -> Vec<ToolMeta> {
             "config.show",
             "cho config show",
             "config",
-            "Show current configuration",
+            "Show current configuration; --effective resolves values with their source",
             true,
         ),
         static_tool(
@@ -140,6 +175,13 @@ pub fn tool_catalog() -> Vec<ToolMeta> {
             "Set configuration key/value",
             true,
         ),
+        static_tool(
+            "config.list-profiles",
+            "cho config list-profiles",
+            "config",
+            "List named config profiles and the active one",
+            true,
+        ),
         static_tool(
             "auth.login",
             "cho auth login",
@@ -198,7 +240,7 @@ pub fn tool_catalog() -> Vec<ToolMeta> {
         ),
         static_tool(
             "reports.balance-sheet",
-            "cho reports balance-sheet",
+            "cho reports balance-sheet [--as-at-date <date>] [--format json|csv]",
             "reports",
             "Get balance sheet report",
             true,
@@ -217,6 +259,20 @@ pub fn tool_catalog() -> Vec<ToolMeta> {
             "Get cashflow report",
             true,
         ),
+        static_tool(
+            "reports.list",
+            "cho reports list",
+            "reports",
+            "List report endpoints available to reports get",
+            true,
+        ),
+        static_tool(
+            "reports.get",
+            "cho reports get <report> [--query <key=value>]",
+            "reports",
+            "Get any report by catalog name or accounting/... API path",
+            true,
+        ),
         static_tool(
             "summary.obligations",
             "cho summary obligations [--user <id>] [--payroll-year <year>] [--details]",
@@ -10,7 +10,8 @@ fn run_raw(home: &Path, args: &[&str]) -> std::process::Output {
         .env("CHO_HOME", home)
         .env_remove("CHO_CLIENT_ID")
         .env_remove("CHO_CLIENT_SECRET")
-        .env_remove("TOOLS_HOME");
+        .env_remove("TOOLS_HOME")
+        .env_remove("CHO_TOKEN_FILE");
 
     cmd.output().expect("command must execute")
 }
@@ -67,7 +68,7 @@ fn tools_json_contains_core_contract_commands() {
     assert_eq!(json["data"]["defaultOutputFormat"], "json");
     assert_eq!(
         json["data"]["outputFormats"],
-        serde_json::json!(["json", "toon"])
+        serde_json::json!(["json", "toon", "bare-json"])
     );
 }
 
@@ -98,6 +99,26 @@ fn group_invocation_prints_group_help() {
     assert!(output.stderr.is_empty());
 }
 
+#[test]
+fn valued_global_flags_do_not_hide_help_fallback() {
+    let home = TempDir::new().expect("temp home");
+
+    for args in [
+        vec!["--page-size", "5"],
+        vec!["--profile", "work"],
+        vec!["--profile=work"],
+        vec!["--profile", "work", "invoices"],
+    ] {
+        let output = run_raw(home.path(), &args);
+
+        assert_eq!(output.status.code(), Some(0), "{args:?}");
+        let stdout = String::from_utf8(output.stdout).expect("stdout should be valid utf8");
+        assert!(stdout.contains("Usage:"), "{args:?}");
+        assert!(stdout.contains("Commands:"), "{args:?}");
+        assert!(output.stderr.is_empty(), "{args:?}");
+    }
+}
+
 #[test]
 fn missing_leaf_argument_uses_native_clap_error() {
     let home = TempDir::new().expect("temp home");
@@ -232,6 +253,58 @@ fn config_show_redacts_client_secret() {
     assert_eq!(show_result["data"]["auth"]["client_secret"], "[REDACTED]");
 }
 
+#[test]
+fn config_show_effective_reports_value_sources() {
+    let home = TempDir::new().expect("temp home");
+
+    let (code, _) = run_json(
+        home.path(),
+        &["config", "set", "auth.client_id", "client-id-123"],
+    );
+    assert_eq!(code, 0);
+    let (code, _) = run_json(
+        home.path(),
+        &["config", "set", "safety.allow_writes", "true"],
+    );
+    assert_eq!(code, 0);
+
+    let (code, json) = run_json(
+        home.path(),
+        &[
+            "--client-secret",
+            "flag-secret",
+            "--limit",
+            "5",
+            "config",
+            "show",
+            "--effective",
+        ],
+    );
+    assert_eq!(code, 0);
+    assert_eq!(json["ok"], true);
+
+    let settings = json["data"]["settings"]
+        .as_array()
+        .expect("settings must be an array");
+    let setting = |key: &str| {
+        settings
+            .iter()
+            .find(|item| item["key"] == key)
+            .unwrap_or_else(|| panic!("missing setting {key}"))
+    };
+
+    assert_eq!(setting("auth.client_id")["value"], "client-id-123");
+    assert_eq!(setting("auth.client_id")["source"], "file");
+    assert_eq!(setting("auth.client_secret")["value"], "[REDACTED]");
+    assert_eq!(setting("auth.client_secret")["source"], "flag");
+    assert_eq!(setting("defaults.limit")["value"], 5);
+    assert_eq!(setting("defaults.limit")["source"], "flag");
+    assert_eq!(setting("safety.allow_writes")["value"], true);
+    assert_eq!(setting("safety.allow_writes")["source"], "file");
+    assert_eq!(setting("sdk.max_retries")["source"], "default");
+    assert!(!json.to_string().contains("flag-secret"));
+}
+
 #[test]
 fn command_execution_writes_history_log_entries() {
     let home = TempDir::new().expect("temp home");
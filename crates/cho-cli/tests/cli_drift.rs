@@ -5,7 +5,7 @@ use std::path::Path;
 use chrono::{Duration, Utc};
 use serde_json::{Value, json};
 use tempfile::TempDir;
-use wiremock::matchers::{body_partial_json, method, path, query_param};
+use wiremock::matchers::{body_partial_json, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 fn run_json(
@@ -17,7 +17,9 @@ fn run_json(
     let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cho");
     cmd.args(args)
         .env("CHO_HOME", home)
-        .env_remove("TOOLS_HOME");
+        .env_remove("TOOLS_HOME")
+        .env_remove("CHO_TOKEN_FILE")
+        .env_remove("CHO_PROFILE");
 
     if with_auth {
         cmd.env("CHO_CLIENT_ID", "test-client-id")
@@ -88,6 +90,7 @@ fn run_help(home: &Path, args: &[&str]) -> (i32, String) {
     cmd.args(args)
         .env("CHO_HOME", home)
         .env_remove("TOOLS_HOME")
+        .env_remove("CHO_TOKEN_FILE")
         .env_remove("CHO_CLIENT_ID")
         .env_remove("CHO_CLIENT_SECRET")
         .env_remove("CHO_BASE_URL");
@@ -98,6 +101,51 @@ fn run_help(home: &Path, args: &[&str]) -> (i32, String) {
     (code, stdout)
 }
 
+#[tokio::test]
+async fn show_request_prints_redacted_request_line_to_stderr() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts"))
+        .and(query_param("view", "active"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "contacts": [] })))
+        .mount(&server)
+        .await;
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cho");
+    let output = cmd
+        .args([
+            "--show-request",
+            "contacts",
+            "list",
+            "--query",
+            "view=active",
+        ])
+        .env("CHO_HOME", home.path())
+        .env("CHO_CLIENT_ID", "test-client-id")
+        .env("CHO_CLIENT_SECRET", "test-client-secret")
+        .env("CHO_BASE_URL", format!("{}/v2/", server.uri()))
+        .env_remove("CHO_TOKEN_FILE")
+        .env_remove("TOOLS_HOME")
+        .output()
+        .expect("command must execute");
+
+    assert_eq!(output.status.code(), Some(0));
+
+    let stdout = String::from_utf8(output.stdout).expect("stdout must be valid utf8");
+    let stderr = String::from_utf8(output.stderr).expect("stderr must be valid utf8");
+    let json =
+        serde_json::from_str::<Value>(&stdout).expect("stdout must stay a valid JSON envelope");
+
+    assert_eq!(json["ok"], true);
+    assert!(stderr.contains(&format!("> GET {}/v2/contacts?", server.uri())));
+    assert!(stderr.contains("view=active"));
+    assert!(stderr.contains("> authorization: Bearer [REDACTED]"));
+    assert!(!stderr.contains("seed-access"));
+}
+
 #[tokio::test]
 async fn verbose_tracing_writes_to_stderr_without_corrupting_json_stdout() {
     let home = TempDir::new().expect("temp home");
@@ -137,6 +185,267 @@ async fn verbose_tracing_writes_to_stderr_without_corrupting_json_stdout() {
     assert!(stderr.contains("api request successful"));
 }
 
+#[tokio::test]
+async fn get_pdf_writes_decoded_bytes_and_reports_metadata() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "access-token", "refresh-token");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/estimates/7/pdf"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "pdf": {
+                "content": "JVBERi0xLjc=",
+                "file_name": "estimate-7.pdf",
+                "content_type": "application/pdf"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let output_path = home.path().join("estimate.pdf");
+    let output_arg = output_path.display().to_string();
+    let base_url = format!("{}/v2/", server.uri());
+    let (code, json, _) = run_json(
+        home.path(),
+        &["estimates", "get-pdf", "7", "--output", &output_arg],
+        true,
+        Some(&base_url),
+    );
+
+    assert_eq!(code, 0);
+    assert_eq!(fs::read(&output_path).expect("pdf written"), b"%PDF-1.7");
+    assert_eq!(json["data"]["bytes"], 8);
+    assert_eq!(json["data"]["file_name"], "estimate-7.pdf");
+    assert_eq!(json["data"]["content_type"], "application/pdf");
+}
+
+#[tokio::test]
+async fn token_file_env_overrides_home_token_store() {
+    let home = TempDir::new().expect("temp home");
+    let secrets = TempDir::new().expect("temp secrets");
+    seed_tokens(home.path(), "home-access", "home-refresh");
+    seed_tokens(secrets.path(), "file-access", "file-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/company"))
+        .and(header("authorization", "Bearer file-access"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "company": { "name": "Example Ltd" }
+        })))
+        .mount(&server)
+        .await;
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cho");
+    let output = cmd
+        .args(["company", "get"])
+        .env("CHO_HOME", home.path())
+        .env("CHO_TOKEN_FILE", secrets.path().join("tokens.json"))
+        .env("CHO_CLIENT_ID", "test-client-id")
+        .env("CHO_CLIENT_SECRET", "test-client-secret")
+        .env("CHO_BASE_URL", format!("{}/v2/", server.uri()))
+        .env_remove("TOOLS_HOME")
+        .output()
+        .expect("command must execute");
+
+    assert_eq!(output.status.code(), Some(0));
+    let json = serde_json::from_slice::<Value>(&output.stdout)
+        .expect("stdout must contain the default JSON envelope");
+    assert_eq!(json["ok"], true);
+    assert_eq!(json["data"]["company"]["name"], "Example Ltd");
+}
+
+#[tokio::test]
+async fn profile_flag_selects_profile_settings_and_token_file() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "home-access", "home-refresh");
+    fs::write(
+        home.path().join("tokens-client-a.json"),
+        fs::read_to_string(home.path().join("tokens.json"))
+            .expect("seeded tokens")
+            .replace("home-access", "profile-access"),
+    )
+    .expect("profile token file should be written");
+    write_config(
+        home.path(),
+        "[profile.client-a]\nclient_id = \"client-a-id\"\nallow_writes = true\n",
+    );
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/company"))
+        .and(header("authorization", "Bearer profile-access"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "company": { "name": "Client A Ltd" }
+        })))
+        .mount(&server)
+        .await;
+
+    let base_url = format!("{}/v2/", server.uri());
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--profile", "client-a", "company", "get"],
+        true,
+        Some(&base_url),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(json["data"]["company"]["name"], "Client A Ltd");
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--profile", "client-a", "config", "show", "--effective"],
+        false,
+        None,
+    );
+    assert_eq!(code, 0);
+    let settings = json["data"]["settings"]
+        .as_array()
+        .expect("settings must be an array");
+    let setting = |key: &str| {
+        settings
+            .iter()
+            .find(|item| item["key"] == key)
+            .unwrap_or_else(|| panic!("{key} missing"))
+            .clone()
+    };
+    assert_eq!(setting("profile")["value"], "client-a");
+    assert_eq!(setting("profile")["source"], "flag");
+    assert_eq!(setting("auth.client_id")["value"], "client-a-id");
+    assert_eq!(setting("auth.client_id")["source"], "profile");
+    assert_eq!(setting("safety.allow_writes")["value"], true);
+    assert_eq!(setting("safety.allow_writes")["source"], "profile");
+    assert!(
+        setting("token_file")["value"]
+            .as_str()
+            .is_some_and(|path| path.ends_with("tokens-client-a.json"))
+    );
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--profile", "missing", "company", "get"],
+        true,
+        Some(&base_url),
+    );
+    assert_eq!(code, 1);
+    assert_eq!(json["ok"], false);
+    assert!(
+        json["error"]["message"]
+            .as_str()
+            .is_some_and(|message| message.contains("configured: client-a"))
+    );
+}
+
+#[test]
+fn profile_base_url_moves_oauth_endpoints_to_the_same_host() {
+    let home = TempDir::new().expect("temp home");
+    write_config(
+        home.path(),
+        "[profile.sandbox]\nbase_url = \"https://api.sandbox.freeagent.com/v2\"\n\n\
+         [profile.custom]\nbase_url = \"https://api.sandbox.freeagent.com/v2/\"\n\
+         token_url = \"https://auth.example.test/token\"\n",
+    );
+
+    let settings = |profile: &str| {
+        let (code, json, _) = run_json(
+            home.path(),
+            &["--profile", profile, "config", "show", "--effective"],
+            false,
+            None,
+        );
+        assert_eq!(code, 0);
+        json["data"]["settings"]
+            .as_array()
+            .expect("settings must be an array")
+            .clone()
+    };
+    let setting = |settings: &[Value], key: &str| {
+        settings
+            .iter()
+            .find(|item| item["key"] == key)
+            .unwrap_or_else(|| panic!("{key} missing"))
+            .clone()
+    };
+
+    let sandbox = settings("sandbox");
+    assert_eq!(
+        setting(&sandbox, "sdk.authorize_url")["value"],
+        "https://api.sandbox.freeagent.com/v2/approve_app"
+    );
+    assert_eq!(setting(&sandbox, "sdk.authorize_url")["source"], "profile");
+    assert_eq!(
+        setting(&sandbox, "sdk.token_url")["value"],
+        "https://api.sandbox.freeagent.com/v2/token_endpoint"
+    );
+    assert_eq!(setting(&sandbox, "sdk.token_url")["source"], "profile");
+
+    let custom = settings("custom");
+    assert_eq!(
+        setting(&custom, "sdk.authorize_url")["value"],
+        "https://api.sandbox.freeagent.com/v2/approve_app"
+    );
+    assert_eq!(
+        setting(&custom, "sdk.token_url")["value"],
+        "https://auth.example.test/token"
+    );
+}
+
+#[test]
+fn config_list_profiles_enumerates_profiles_and_redacts_secrets() {
+    let home = TempDir::new().expect("temp home");
+
+    for (key, value) in [
+        (
+            "profile.demo.base_url",
+            "https://api.sandbox.freeagent.com/v2/",
+        ),
+        ("profile.prod.client_id", "prod-id"),
+        ("profile.prod.client_secret", "prod-secret"),
+    ] {
+        let (code, json, _) = run_json(home.path(), &["config", "set", key, value], false, None);
+        assert_eq!(code, 0, "{key}");
+        assert_eq!(json["ok"], true);
+    }
+    let (code, json, _) = run_json(
+        home.path(),
+        &["config", "set", "profile.../x.client_id", "bad"],
+        false,
+        None,
+    );
+    assert_eq!(code, 1);
+    assert_eq!(json["ok"], false);
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--profile", "prod", "config", "list-profiles"],
+        false,
+        None,
+    );
+    assert_eq!(code, 0);
+    assert_eq!(json["data"]["active"], "prod");
+    let profiles = json["data"]["profiles"]
+        .as_array()
+        .expect("profiles must be an array");
+    assert_eq!(profiles.len(), 2);
+    assert_eq!(profiles[0]["name"], "demo");
+    assert_eq!(profiles[0]["active"], false);
+    assert_eq!(profiles[1]["name"], "prod");
+    assert_eq!(profiles[1]["active"], true);
+    assert_eq!(profiles[1]["client_id"], "prod-id");
+    assert!(!json.to_string().contains("prod-secret"));
+
+    let (code, json, _) = run_json(home.path(), &["config", "show"], false, None);
+    assert_eq!(code, 0);
+    assert_eq!(
+        json["data"]["profile"]["prod"]["client_secret"],
+        "[REDACTED]"
+    );
+
+    let history =
+        fs::read_to_string(home.path().join("history.log")).expect("history log should be written");
+    assert!(!history.contains("prod-secret"));
+}
+
 #[test]
 fn tools_registry_has_unique_names_and_json_examples() {
     let home = TempDir::new().expect("temp home");
@@ -182,7 +491,20 @@ fn tools_registry_has_unique_names_and_json_examples() {
         .map(|item| item["name"].as_str().unwrap_or_default())
         .collect::<HashSet<_>>();
 
-    for required in ["--toon", "--limit", "--all", "--verbose", "--precise"] {
+    for required in [
+        "--toon",
+        "--no-envelope",
+        "--limit",
+        "--all",
+        "--page-size",
+        "--count-only",
+        "--progress",
+        "--dry-run",
+        "--profile",
+        "--show-request",
+        "--verbose",
+        "--precise",
+    ] {
         assert!(
             global_flags.contains(required),
             "missing global flag metadata for {required}"
@@ -201,7 +523,10 @@ fn tools_registry_has_unique_names_and_json_examples() {
         "removed --format flag should not be advertised"
     );
     assert_eq!(json["data"]["defaultOutputFormat"], "json");
-    assert_eq!(json["data"]["outputFormats"], json!(["json", "toon"]));
+    assert_eq!(
+        json["data"]["outputFormats"],
+        json!(["json", "toon", "bare-json"])
+    );
 }
 
 #[test]
@@ -285,6 +610,36 @@ async fn bank_transactions_list_without_filter_merges_accounts_sorted_newest_fir
     assert_eq!(data[1]["_bank_account_name"], "Wise GBP");
 }
 
+#[tokio::test]
+async fn bank_transactions_list_expands_bare_bank_account_id_to_url() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/bank_transactions"))
+        .and(query_param(
+            "bank_account",
+            format!("{}/v2/bank_accounts/7", server.uri()),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "bank_transactions": [{ "url": "tx-1", "dated_on": "2026-01-02" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &["bank-transactions", "list", "--bank-account", "7"],
+        true,
+        Some(&format!("{}/v2/", server.uri())),
+    );
+
+    assert_eq!(code, 0);
+    assert_eq!(json["data"][0]["url"], "tx-1");
+}
+
 #[tokio::test]
 async fn bank_transactions_for_approval_uses_marked_for_review_view() {
     let home = TempDir::new().expect("temp home");
@@ -808,6 +1163,119 @@ async fn invoices_list_supports_unpaid_only_client_side_filter() {
     assert_eq!(json["meta"]["total"], 1);
 }
 
+#[tokio::test]
+async fn page_size_flag_sets_per_page_independently_of_limit() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts"))
+        .and(query_param("page", "1"))
+        .and(query_param("per_page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!("<{}/v2/contacts?page=2>; rel=\"next\"", server.uri()),
+                )
+                .set_body_json(json!({
+                    "contacts": [{ "url": "c-1" }, { "url": "c-2" }]
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts"))
+        .and(query_param("page", "2"))
+        .and(query_param("per_page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "contacts": [{ "url": "c-3" }, { "url": "c-4" }]
+        })))
+        .mount(&server)
+        .await;
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--page-size", "2", "--limit", "3", "contacts", "list"],
+        true,
+        Some(&format!("{}/v2/", server.uri())),
+    );
+
+    assert_eq!(code, 0);
+    assert_eq!(json["meta"]["count"], 3);
+    assert_eq!(json["meta"]["hasMore"], true);
+}
+
+#[tokio::test]
+async fn include_archived_requests_all_view_and_conflicts_with_view() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts"))
+        .and(query_param("view", "all"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "contacts": [{ "url": "c-1", "status": "Hidden" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let base_url = format!("{}/v2/", server.uri());
+    let (code, json, _) = run_json(
+        home.path(),
+        &["contacts", "list", "--include-archived"],
+        true,
+        Some(&base_url),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(json["meta"]["count"], 1);
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cho");
+    let output = cmd
+        .args(["contacts", "list", "--include-archived", "--view", "active"])
+        .env("CHO_HOME", home.path())
+        .env_remove("TOOLS_HOME")
+        .output()
+        .expect("command must execute");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+}
+
+#[tokio::test]
+async fn success_meta_reports_rate_limit_headers_when_present() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/company"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-RateLimit-Limit", "120")
+                .insert_header("X-RateLimit-Remaining", "118")
+                .set_body_json(json!({ "company": { "name": "Example Ltd" } })),
+        )
+        .mount(&server)
+        .await;
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &["company", "get"],
+        true,
+        Some(&format!("{}/v2/", server.uri())),
+    );
+
+    assert_eq!(code, 0);
+    assert_eq!(json["meta"]["rateLimit"]["limit"], 120);
+    assert_eq!(json["meta"]["rateLimit"]["remaining"], 118);
+    assert!(json["meta"]["rateLimit"].get("resetSecs").is_none());
+    assert!(json["meta"]["rateLimit"].get("retryAfter").is_none());
+}
+
 #[tokio::test]
 async fn summary_receivables_returns_compact_totals() {
     let home = TempDir::new().expect("temp home");
@@ -1075,6 +1543,92 @@ fn mutating_commands_are_blocked_when_write_gate_is_disabled() {
     assert_eq!(json["error"]["code"], "write_not_allowed");
 }
 
+#[tokio::test]
+async fn explicit_allow_writes_false_blocks_writes_before_any_http_request() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    write_config(home.path(), "[safety]\nallow_writes = false\n");
+    let payload_path = home.path().join("contact.json");
+    fs::write(&payload_path, r#"{"organisation_name":"Acme Ltd"}"#)
+        .expect("payload should be written");
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/contacts"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "contact": {} })))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &[
+            "contacts",
+            "create",
+            "--file",
+            payload_path.to_str().expect("utf8 path"),
+        ],
+        true,
+        Some(&format!("{}/v2/", server.uri())),
+    );
+
+    assert_eq!(code, 2);
+    assert_eq!(json["error"]["code"], "write_not_allowed");
+    assert!(
+        json["error"]["message"]
+            .as_str()
+            .unwrap_or_default()
+            .contains("[safety] allow_writes = true")
+    );
+}
+
+#[test]
+fn dry_run_create_previews_wrapped_body_without_write_gate_or_http() {
+    let home = TempDir::new().expect("temp home");
+    let payload_path = home.path().join("contact.json");
+    fs::write(
+        &payload_path,
+        serde_json::to_string(&json!({ "organisation_name": "Example Ltd" }))
+            .expect("payload json"),
+    )
+    .expect("payload file should be written");
+
+    let payload_arg = payload_path.to_string_lossy().to_string();
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--dry-run", "contacts", "create", "--file", &payload_arg],
+        true,
+        Some("http://127.0.0.1:9/v2/"),
+    );
+
+    assert_eq!(code, 0);
+    assert_eq!(json["ok"], true);
+    assert_eq!(json["meta"]["dryRun"], true);
+    assert_eq!(json["data"]["method"], "POST");
+    assert_eq!(json["data"]["path"], "contacts");
+    assert_eq!(
+        json["data"]["body"],
+        json!({ "contact": { "organisation_name": "Example Ltd" } })
+    );
+}
+
+#[test]
+fn dry_run_is_rejected_for_writes_without_preview_support() {
+    let home = TempDir::new().expect("temp home");
+    enable_writes(home.path());
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--dry-run", "timeslips", "start-timer", "42"],
+        true,
+        Some("http://127.0.0.1:9/v2/"),
+    );
+
+    assert_eq!(code, 1);
+    assert_eq!(json["ok"], false);
+    assert_eq!(json["error"]["code"], "usage_error");
+}
+
 #[tokio::test]
 async fn update_explanation_accepts_local_attachment_path_and_partial_fields() {
     let home = TempDir::new().expect("temp home");
@@ -1218,6 +1772,85 @@ async fn reports_cashflow_defaults_to_months_12_when_no_dates_are_provided() {
     assert_eq!(json["data"]["cashflow"]["balance"], "123.45");
 }
 
+#[tokio::test]
+async fn reports_balance_sheet_csv_format_embeds_flat_rows_in_envelope() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/accounting/balance_sheet"))
+        .and(query_param("as_at_date", "2026-03-31"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "balance_sheet": {
+                "fixed_assets": {
+                    "accounts": [
+                        { "name": "Computer Equipment, Cost", "total": "2400.00" }
+                    ]
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &[
+            "reports",
+            "balance-sheet",
+            "--as-at-date",
+            "2026-03-31",
+            "--format",
+            "csv",
+        ],
+        true,
+        Some(&format!("{}/v2/", server.uri())),
+    );
+
+    assert_eq!(code, 0);
+    assert_eq!(json["ok"], true);
+    assert_eq!(json["data"]["format"], "csv");
+    assert_eq!(json["data"]["rows"], 3);
+    assert_eq!(
+        json["data"]["content"],
+        "level,label,value\r\n0,fixed_assets,\r\n1,accounts,\r\n2,\"Computer Equipment, Cost\",2400.00\r\n"
+    );
+}
+
+#[tokio::test]
+async fn reports_get_fetches_accounting_path_with_query_pairs() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/accounting/profit_and_loss"))
+        .and(query_param("accounting_period", "2025/26"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "profit_and_loss": { "income": "1000.00" }
+        })))
+        .mount(&server)
+        .await;
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &[
+            "reports",
+            "get",
+            "accounting/profit_and_loss",
+            "--query",
+            "accounting_period=2025/26",
+        ],
+        true,
+        Some(&format!("{}/v2/", server.uri())),
+    );
+
+    assert_eq!(code, 0);
+    assert_eq!(json["ok"], true);
+    assert_eq!(json["meta"]["tool"], "reports.get");
+    assert_eq!(json["data"]["profit_and_loss"]["income"], "1000.00");
+}
+
 #[test]
 fn help_for_read_only_resources_hides_mutating_commands() {
     let home = TempDir::new().expect("temp home");
@@ -1512,3 +2145,218 @@ async fn create_resource_accepts_query_pairs_for_company_api_edge_cases() {
     assert_eq!(json["ok"], true);
     assert_eq!(json["data"]["url"], "https://api.freeagent.com/v2/notes/99");
 }
+
+#[tokio::test]
+async fn count_only_fetches_one_item_and_emits_total_count() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts"))
+        .and(query_param("page", "1"))
+        .and(query_param("per_page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Total-Count", "42")
+                .insert_header(
+                    "Link",
+                    "<https://example.test/v2/contacts?page=2>; rel=\"next\"",
+                )
+                .set_body_json(json!({ "contacts": [{ "url": "c-1" }] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let base_url = format!("{}/v2/", server.uri());
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--count-only", "contacts", "list"],
+        true,
+        Some(&base_url),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(json["data"], json!({ "count": 42 }));
+    assert_eq!(json["meta"]["total"], 42);
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cho");
+    let output = cmd
+        .args(["--count-only", "--all", "contacts", "list"])
+        .env("CHO_HOME", home.path())
+        .output()
+        .expect("command must execute");
+    assert_ne!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+}
+
+#[tokio::test]
+async fn count_only_is_rejected_outside_list_commands_and_beats_per_page() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts"))
+        .and(query_param("per_page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Total-Count", "7")
+                .set_body_json(json!({ "contacts": [{ "url": "c-1" }] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let base_url = format!("{}/v2/", server.uri());
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--count-only", "contacts", "search", "acme"],
+        true,
+        Some(&base_url),
+    );
+    assert_eq!(code, 1);
+    assert_eq!(json["ok"], false);
+    assert!(
+        json["error"]["message"]
+            .as_str()
+            .is_some_and(|message| message.contains("invalid option --count-only"))
+    );
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--count-only", "company", "get"],
+        true,
+        Some(&base_url),
+    );
+    assert_eq!(code, 1);
+    assert_eq!(json["ok"], false);
+
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--count-only", "contacts", "list", "--per-page", "50"],
+        true,
+        Some(&base_url),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(json["data"], json!({ "count": 7 }));
+}
+
+#[tokio::test]
+async fn no_envelope_emits_bare_data_and_sends_errors_to_stderr() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "contacts": [{ "url": "c-1" }, { "url": "c-2" }] })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts/404"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let base_url = format!("{}/v2/", server.uri());
+    let (code, json, _) = run_json(
+        home.path(),
+        &["--no-envelope", "contacts", "list"],
+        true,
+        Some(&base_url),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(json, json!([{ "url": "c-1" }, { "url": "c-2" }]));
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cho");
+    let output = cmd
+        .args(["--no-envelope", "contacts", "get", "404"])
+        .env("CHO_HOME", home.path())
+        .env("CHO_CLIENT_ID", "test-client-id")
+        .env("CHO_CLIENT_SECRET", "test-client-secret")
+        .env("CHO_BASE_URL", &base_url)
+        .env_remove("TOOLS_HOME")
+        .env_remove("CHO_TOKEN_FILE")
+        .output()
+        .expect("command must execute");
+    assert_ne!(output.status.code(), Some(0));
+    assert!(output.stdout.is_empty());
+    let error = serde_json::from_slice::<Value>(&output.stderr).expect("stderr error envelope");
+    assert_eq!(error["ok"], false);
+    assert_eq!(error["error"]["code"], "not_found");
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cho");
+    let output = cmd
+        .args(["--no-envelope", "tools", "missing.tool"])
+        .env("CHO_HOME", home.path())
+        .env_remove("TOOLS_HOME")
+        .output()
+        .expect("command must execute");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let error = serde_json::from_slice::<Value>(&output.stderr).expect("stderr error envelope");
+    assert_eq!(error["error"]["code"], "not_found");
+}
+
+#[tokio::test]
+async fn progress_flag_reports_pages_on_stderr_and_keeps_stdout_an_envelope() {
+    let home = TempDir::new().expect("temp home");
+    seed_tokens(home.path(), "seed-access", "seed-refresh");
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts"))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Total-Count", "3")
+                .insert_header(
+                    "Link",
+                    format!("<{}/v2/contacts?page=2>; rel=\"next\"", server.uri()),
+                )
+                .set_body_json(json!({ "contacts": [{ "url": "c-1" }, { "url": "c-2" }] })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "contacts": [{ "url": "c-3" }] })),
+        )
+        .mount(&server)
+        .await;
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cho");
+    let output = cmd
+        .args([
+            "--progress",
+            "--all",
+            "--page-size",
+            "2",
+            "contacts",
+            "list",
+        ])
+        .env("CHO_HOME", home.path())
+        .env("CHO_CLIENT_ID", "test-client-id")
+        .env("CHO_CLIENT_SECRET", "test-client-secret")
+        .env("CHO_BASE_URL", format!("{}/v2/", server.uri()))
+        .env_remove("TOOLS_HOME")
+        .env_remove("CHO_TOKEN_FILE")
+        .output()
+        .expect("command must execute");
+
+    assert_eq!(output.status.code(), Some(0));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("fetched 2/3 contacts (page 1)"), "{stderr}");
+    assert!(stderr.contains("fetched 3/3 contacts (page 2)"), "{stderr}");
+    let json = serde_json::from_slice::<Value>(&output.stdout).expect("stdout envelope");
+    assert_eq!(json["ok"], true);
+    assert_eq!(json["data"].as_array().map(Vec::len), Some(3));
+}
@@ -0,0 +1,116 @@
+//! Ledger export helpers over the `transactions` resource.
+
+use crate::client::FreeAgentClient;
+use crate::error::{ChoSdkError, Result};
+use crate::models::dates::ApiDate;
+use crate::models::journal::AccountingTransaction;
+
+use super::specs::{ResourceSpec, by_name};
+
+const PAGE_SIZE: u32 = 100;
+
+/// Filters for a ledger export.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountingTransactionQuery {
+    /// First posting date to include.
+    pub from_date: Option<ApiDate>,
+    /// Last posting date to include.
+    pub to_date: Option<ApiDate>,
+    /// Restricts the export to one nominal code.
+    pub nominal_code: Option<String>,
+}
+
+impl AccountingTransactionQuery {
+    fn pairs(&self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+        if let Some(date) = self.from_date {
+            query.push(("from_date".to_string(), date.to_string()));
+        }
+        if let Some(date) = self.to_date {
+            query.push(("to_date".to_string(), date.to_string()));
+        }
+        if let Some(code) = &self.nominal_code {
+            query.push(("nominal_code".to_string(), code.clone()));
+        }
+        query
+    }
+}
+
+/// Accounting transactions API for exporting every posting in a period.
+pub struct AccountingTransactionsApi<'a> {
+    client: &'a FreeAgentClient,
+}
+
+impl<'a> AccountingTransactionsApi<'a> {
+    /// Creates a new accounting transactions API wrapper.
+    pub(crate) fn new(client: &'a FreeAgentClient) -> Self {
+        Self { client }
+    }
+
+    /// Fetches one page (1-based) of ledger lines.
+    ///
+    /// Returns the typed lines together with the page to request next,
+    /// taken from the response's `Link` header.
+    pub async fn list(
+        &self,
+        query: &AccountingTransactionQuery,
+        page: u32,
+    ) -> Result<(Vec<AccountingTransaction>, Option<u32>)> {
+        let result = self
+            .client
+            .resource(transactions_spec()?)
+            .list_page(&query.pairs(), page, PAGE_SIZE)
+            .await?;
+        let next = result.next_page();
+        let items = result
+            .items
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| ChoSdkError::Parse {
+                message: format!("Failed to parse accounting transactions: {e}"),
+            })?;
+        Ok((items, next))
+    }
+
+    /// Returns a cursor that walks pages while FreeAgent advertises a next one.
+    ///
+    /// Unlike [`FreeAgentClient::list_paginated`], nothing is buffered: each
+    /// call to [`AccountingTransactionPages::next_page`] fetches and yields
+    /// one page, so exports of a full ledger stay within constant memory.
+    pub fn stream_all(&self, query: AccountingTransactionQuery) -> AccountingTransactionPages<'a> {
+        AccountingTransactionPages {
+            api: AccountingTransactionsApi {
+                client: self.client,
+            },
+            query,
+            next: Some(1),
+        }
+    }
+}
+
+/// Page cursor returned by [`AccountingTransactionsApi::stream_all`].
+pub struct AccountingTransactionPages<'a> {
+    api: AccountingTransactionsApi<'a>,
+    query: AccountingTransactionQuery,
+    next: Option<u32>,
+}
+
+impl AccountingTransactionPages<'_> {
+    /// Fetches the next page, or `None` once the ledger is exhausted.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<AccountingTransaction>>> {
+        let Some(page) = self.next else {
+            return Ok(None);
+        };
+
+        let (items, next) = self.api.list(&self.query, page).await?;
+        self.next = next;
+        Ok((!items.is_empty()).then_some(items))
+    }
+}
+
+fn transactions_spec() -> Result<ResourceSpec> {
+    by_name("transactions").ok_or_else(|| ChoSdkError::Config {
+        message: "Missing transactions resource spec".to_string(),
+    })
+}
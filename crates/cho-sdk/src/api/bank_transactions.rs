@@ -0,0 +1,110 @@
+//! Bank transaction list and statement upload helpers.
+
+use crate::client::FreeAgentClient;
+use crate::error::{ChoSdkError, Result};
+use crate::models::bank_transaction::StatementLine;
+use crate::models::batch::{BatchFailure, BatchResult};
+use crate::models::{ListResult, Pagination};
+
+use super::resource::resource_url;
+use super::specs::by_name;
+
+/// Bank transaction API scoped to individual bank accounts.
+pub struct BankTransactionsApi<'a> {
+    client: &'a FreeAgentClient,
+}
+
+impl<'a> BankTransactionsApi<'a> {
+    /// Creates a new bank transaction API wrapper.
+    pub(crate) fn new(client: &'a FreeAgentClient) -> Self {
+        Self { client }
+    }
+
+    /// Lists transactions for one bank account (id or URL) with extra query params.
+    pub async fn list_for_account_with_query(
+        &self,
+        bank_account: &str,
+        query: &[(String, String)],
+        pagination: Pagination,
+    ) -> Result<ListResult> {
+        let spec = by_name("bank-transactions").ok_or_else(|| ChoSdkError::Config {
+            message: "Missing bank-transactions resource spec".to_string(),
+        })?;
+        let account_url = resource_url(
+            &self.client.config().base_url,
+            "bank_accounts",
+            bank_account,
+        )?;
+
+        let mut scoped = query
+            .iter()
+            .filter(|(key, _)| key != "bank_account")
+            .cloned()
+            .collect::<Vec<_>>();
+        scoped.insert(0, ("bank_account".to_string(), account_url));
+
+        self.client.resource(spec).list(&scoped, pagination).await
+    }
+
+    /// Lists transactions for one bank account (id or URL).
+    pub async fn list_for_account(
+        &self,
+        bank_account: &str,
+        pagination: Pagination,
+    ) -> Result<ListResult> {
+        self.list_for_account_with_query(bank_account, &[], pagination)
+            .await
+    }
+
+    /// Uploads statement lines to one bank account (id or URL) in a single request.
+    ///
+    /// Lines failing local validation are reported in [`BatchResult::failed`]
+    /// and left out of the upload. FreeAgent accepts or rejects the remaining
+    /// lines as a whole, so an API error fails the call. The upload response
+    /// carries no transactions, so accepted lines are listed by index in
+    /// [`BatchResult::submitted`] rather than in [`BatchResult::succeeded`].
+    pub async fn create_many(
+        &self,
+        bank_account: &str,
+        lines: &[StatementLine],
+    ) -> Result<BatchResult> {
+        let account_url = resource_url(
+            &self.client.config().base_url,
+            "bank_accounts",
+            bank_account,
+        )?;
+
+        let mut result = BatchResult::default();
+        let mut statement = Vec::new();
+        let mut submitted = Vec::new();
+        for (index, line) in lines.iter().enumerate() {
+            match line.validate() {
+                Ok(()) => {
+                    statement.push(serde_json::to_value(line).map_err(|e| ChoSdkError::Parse {
+                        message: format!("Failed to serialize statement line: {e}"),
+                    })?);
+                    submitted.push(index);
+                }
+                Err(message) => result.failed.push(BatchFailure {
+                    index,
+                    error: format!("invalid statement line: {message}"),
+                }),
+            }
+        }
+
+        if statement.is_empty() {
+            return Ok(result);
+        }
+
+        self.client
+            .post_json_with_query(
+                "bank_transactions/statement",
+                &[("bank_account".to_string(), account_url)],
+                &serde_json::json!({ "statement": statement }),
+                true,
+            )
+            .await?;
+        result.submitted = submitted;
+        Ok(result)
+    }
+}
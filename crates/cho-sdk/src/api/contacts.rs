@@ -0,0 +1,102 @@
+//! Contact API helpers.
+
+use serde_json::Value;
+
+use crate::client::FreeAgentClient;
+use crate::error::{ChoSdkError, Result};
+use crate::models::batch::{BatchFailure, BatchResult};
+use crate::models::contact::{Contact, ContactDetail};
+
+use super::specs::by_name;
+
+/// Contact helpers layered over the generic resource API.
+pub struct ContactsApi<'a> {
+    client: &'a FreeAgentClient,
+}
+
+impl<'a> ContactsApi<'a> {
+    /// Creates a new contacts API wrapper.
+    pub(crate) fn new(client: &'a FreeAgentClient) -> Self {
+        Self { client }
+    }
+
+    /// Fetches one contact with its status, balance, and project count.
+    pub async fn get_full(&self, id: &str) -> Result<ContactDetail> {
+        let spec = by_name("contacts").ok_or_else(|| ChoSdkError::Config {
+            message: "Missing contacts resource spec".to_string(),
+        })?;
+        let contact = self.client.resource(spec).get(id).await?;
+        serde_json::from_value(contact).map_err(|e| ChoSdkError::Parse {
+            message: format!("Failed to parse contact: {e}"),
+        })
+    }
+
+    /// Validates and creates one contact.
+    pub async fn create(&self, contact: &Contact) -> Result<Value> {
+        contact
+            .validate_for_create()
+            .map_err(|message| ChoSdkError::Validation {
+                resource: "contact".to_string(),
+                errors: vec![message],
+            })?;
+
+        let spec = by_name("contacts").ok_or_else(|| ChoSdkError::Config {
+            message: "Missing contacts resource spec".to_string(),
+        })?;
+        let body = serde_json::to_value(contact).map_err(|e| ChoSdkError::Parse {
+            message: format!("Failed to serialize contact: {e}"),
+        })?;
+
+        self.client.resource(spec).create(&body).await
+    }
+
+    /// Creates contacts one request at a time, collecting per-row failures.
+    ///
+    /// FreeAgent has no bulk contact endpoint. Rows failing local validation
+    /// or rejected by the API are reported in [`BatchResult::failed`]. Write
+    /// gate and auth errors abort the batch; rate limits and network failures
+    /// stop it, leaving later rows in [`BatchResult::not_attempted`].
+    pub async fn create_many(&self, contacts: &[Contact]) -> Result<BatchResult> {
+        let spec = by_name("contacts").ok_or_else(|| ChoSdkError::Config {
+            message: "Missing contacts resource spec".to_string(),
+        })?;
+        let resource = self.client.resource(spec);
+
+        let mut result = BatchResult::default();
+        for (index, contact) in contacts.iter().enumerate() {
+            if let Err(message) = contact.validate_for_create() {
+                result.failed.push(BatchFailure {
+                    index,
+                    error: format!("invalid contact: {message}"),
+                });
+                continue;
+            }
+            let body = serde_json::to_value(contact).map_err(|e| ChoSdkError::Parse {
+                message: format!("Failed to serialize contact: {e}"),
+            })?;
+
+            match resource.create(&body).await {
+                Ok(created) => result.succeeded.push(created),
+                Err(
+                    err @ (ChoSdkError::WriteNotAllowed { .. }
+                    | ChoSdkError::AuthRequired { .. }
+                    | ChoSdkError::TokenExpired { .. }),
+                ) => return Err(err),
+                Err(err @ (ChoSdkError::RateLimited { .. } | ChoSdkError::Network(_))) => {
+                    result.failed.push(BatchFailure {
+                        index,
+                        error: err.to_string(),
+                    });
+                    result.not_attempted = (index + 1..contacts.len()).collect();
+                    break;
+                }
+                Err(err) => result.failed.push(BatchFailure {
+                    index,
+                    error: err.to_string(),
+                }),
+            }
+        }
+
+        Ok(result)
+    }
+}
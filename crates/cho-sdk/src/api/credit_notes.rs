@@ -0,0 +1,52 @@
+//! Credit note document helpers.
+
+use crate::client::FreeAgentClient;
+use crate::error::{ChoSdkError, Result};
+use crate::models::credit_note::CreditNoteEmail;
+
+use super::specs::{ResourceSpec, by_name};
+
+/// Credit note PDF and email helpers.
+pub struct CreditNotesApi<'a> {
+    client: &'a FreeAgentClient,
+}
+
+impl<'a> CreditNotesApi<'a> {
+    /// Creates a new credit notes API wrapper.
+    pub(crate) fn new(client: &'a FreeAgentClient) -> Self {
+        Self { client }
+    }
+
+    /// Fetches a credit note rendered as PDF bytes.
+    pub async fn get_pdf(&self, id: &str) -> Result<Vec<u8>> {
+        self.client.resource(credit_notes_spec()?).get_pdf(id).await
+    }
+
+    /// Emails a credit note to its contact, or to the addresses in `email`.
+    pub async fn email(&self, id: &str, email: &CreditNoteEmail) -> Result<()> {
+        email
+            .validate()
+            .map_err(|message| ChoSdkError::Validation {
+                resource: "credit note email".to_string(),
+                errors: vec![message],
+            })?;
+
+        self.client
+            .resource(credit_notes_spec()?)
+            .action(
+                id,
+                reqwest::Method::POST,
+                "send_email",
+                Some(&email.to_payload()),
+                true,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+fn credit_notes_spec() -> Result<ResourceSpec> {
+    by_name("credit-notes").ok_or_else(|| ChoSdkError::Config {
+        message: "Missing credit-notes resource spec".to_string(),
+    })
+}
@@ -0,0 +1,159 @@
+//! Typed invoice list helpers.
+
+use chrono::NaiveDate;
+use serde_json::Value;
+
+use crate::client::FreeAgentClient;
+use crate::error::{ChoSdkError, Result};
+use crate::models::invoice::{Invoice, InvoiceStatus};
+use crate::models::{ListResult, Pagination};
+
+use super::resource::resource_url;
+use super::specs::{ResourceSpec, by_name};
+
+/// Invoice API with typed filters for common list queries.
+pub struct InvoicesApi<'a> {
+    client: &'a FreeAgentClient,
+}
+
+impl<'a> InvoicesApi<'a> {
+    /// Creates a new invoice API wrapper.
+    pub(crate) fn new(client: &'a FreeAgentClient) -> Self {
+        Self { client }
+    }
+
+    /// Lists invoices using raw query params.
+    pub async fn list(
+        &self,
+        query: &[(String, String)],
+        pagination: Pagination,
+    ) -> Result<ListResult> {
+        self.client
+            .resource(invoices_spec()?)
+            .list(query, pagination)
+            .await
+    }
+
+    /// Validates and creates one invoice.
+    ///
+    /// Local validation reports every missing or malformed field in one
+    /// error before any request is sent.
+    pub async fn create(&self, invoice: &Invoice) -> Result<Value> {
+        invoice
+            .validate_for_create()
+            .map_err(|errors| ChoSdkError::Validation {
+                resource: "invoice".to_string(),
+                errors,
+            })?;
+
+        let body = serde_json::to_value(invoice).map_err(|e| ChoSdkError::Parse {
+            message: format!("Failed to serialize invoice: {e}"),
+        })?;
+
+        self.client.resource(invoices_spec()?).create(&body).await
+    }
+
+    /// Lists invoices in one status view.
+    pub async fn list_by_status(
+        &self,
+        status: InvoiceStatus,
+        pagination: Pagination,
+    ) -> Result<ListResult> {
+        self.list(&status_query(status), pagination).await
+    }
+
+    /// Lists invoices raised against one contact (id or URL).
+    pub async fn list_for_contact(
+        &self,
+        contact: &str,
+        pagination: Pagination,
+    ) -> Result<ListResult> {
+        let query = contact_query(&self.client.config().base_url, contact)?;
+        self.list(&query, pagination).await
+    }
+
+    /// Lists invoices dated within an inclusive date range.
+    pub async fn list_in_date_range(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        pagination: Pagination,
+    ) -> Result<ListResult> {
+        self.list(&date_range_query(from, to)?, pagination).await
+    }
+
+    /// Marks an invoice as sent without emailing it.
+    ///
+    /// Uses FreeAgent's `mark_as_sent` transition, so no other invoice fields
+    /// are rewritten.
+    pub async fn mark_sent(&self, id: &str) -> Result<Value> {
+        self.client
+            .resource(invoices_spec()?)
+            .action(
+                id,
+                reqwest::Method::PUT,
+                "transitions/mark_as_sent",
+                None,
+                true,
+            )
+            .await
+    }
+}
+
+fn invoices_spec() -> Result<ResourceSpec> {
+    by_name("invoices").ok_or_else(|| ChoSdkError::Config {
+        message: "Missing invoices resource spec".to_string(),
+    })
+}
+
+fn status_query(status: InvoiceStatus) -> Vec<(String, String)> {
+    vec![("view".to_string(), status.as_view().to_string())]
+}
+
+fn contact_query(base_url: &str, contact: &str) -> Result<Vec<(String, String)>> {
+    Ok(vec![(
+        "contact".to_string(),
+        resource_url(base_url, "contacts", contact)?,
+    )])
+}
+
+fn date_range_query(from: NaiveDate, to: NaiveDate) -> Result<Vec<(String, String)>> {
+    if from > to {
+        return Err(ChoSdkError::Config {
+            message: format!("Invalid date range: {from} is after {to}"),
+        });
+    }
+
+    Ok(vec![
+        ("from_date".to_string(), from.format("%Y-%m-%d").to_string()),
+        ("to_date".to_string(), to.format("%Y-%m-%d").to_string()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_range_query_formats_dates_and_rejects_inverted_ranges() {
+        let from = NaiveDate::from_ymd_opt(2026, 1, 1).expect("date");
+        let to = NaiveDate::from_ymd_opt(2026, 3, 31).expect("date");
+
+        assert_eq!(
+            date_range_query(from, to).expect("range"),
+            vec![
+                ("from_date".to_string(), "2026-01-01".to_string()),
+                ("to_date".to_string(), "2026-03-31".to_string()),
+            ]
+        );
+        assert!(date_range_query(to, from).is_err());
+    }
+
+    #[test]
+    fn status_query_uses_freeagent_view_names() {
+        assert_eq!(
+            status_query(InvoiceStatus::OpenOrOverdue),
+            vec![("view".to_string(), "open_or_overdue".to_string())]
+        );
+    }
+}
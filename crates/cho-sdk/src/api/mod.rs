@@ -1,7 +1,27 @@
 //! API namespace helpers.
 
+pub mod accounting_transactions;
+pub mod bank_transactions;
+pub mod contacts;
+pub mod credit_notes;
+pub mod invoices;
+pub mod payments;
+pub mod reports;
 pub mod resource;
+pub mod sales_tax_periods;
 pub mod specs;
+pub mod stock_items;
 
-pub use resource::ResourceApi;
+pub use accounting_transactions::{
+    AccountingTransactionPages, AccountingTransactionQuery, AccountingTransactionsApi,
+};
+pub use bank_transactions::BankTransactionsApi;
+pub use contacts::ContactsApi;
+pub use credit_notes::CreditNotesApi;
+pub use invoices::InvoicesApi;
+pub use payments::PaymentsApi;
+pub use reports::{REPORTS, ReportMeta, ReportsApi};
+pub use resource::{PdfDocument, ResourceApi};
+pub use sales_tax_periods::SalesTaxPeriodsApi;
 pub use specs::{RESOURCES, ResourceCapabilities, ResourceSpec, by_name};
+pub use stock_items::StockItemsApi;
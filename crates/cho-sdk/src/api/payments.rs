@@ -0,0 +1,81 @@
+//! Invoice payment API.
+
+use serde_json::Value;
+
+use crate::client::FreeAgentClient;
+use crate::error::{ChoSdkError, Result};
+use crate::models::payment::Payment;
+
+use super::specs::by_name;
+
+/// Payment API backed by bank transaction explanations.
+pub struct PaymentsApi<'a> {
+    client: &'a FreeAgentClient,
+}
+
+impl<'a> PaymentsApi<'a> {
+    /// Creates a new payment API wrapper.
+    pub(crate) fn new(client: &'a FreeAgentClient) -> Self {
+        Self { client }
+    }
+
+    /// Lists invoice payments explaining one bank transaction.
+    ///
+    /// A deposit covering several invoices is a single bank transaction with
+    /// one explanation per invoice; this returns those explanations, each
+    /// tagged with the transaction URL. Non-invoice explanations are skipped.
+    pub async fn for_batch(&self, bank_transaction: &str) -> Result<Vec<Payment>> {
+        let spec = by_name("bank-transactions").ok_or_else(|| ChoSdkError::Config {
+            message: "Missing bank-transactions resource spec".to_string(),
+        })?;
+        let transaction = self.client.resource(spec).get(bank_transaction).await?;
+        let transaction_url = transaction
+            .get("url")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let explanations = transaction
+            .get("bank_transaction_explanations")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let mut payments = Vec::new();
+        for explanation in explanations {
+            let mut payment =
+                serde_json::from_value::<Payment>(explanation.clone()).map_err(|e| {
+                    ChoSdkError::Parse {
+                        message: format!("Failed to parse bank transaction explanation: {e}"),
+                    }
+                })?;
+            if payment.paid_invoice.is_none() {
+                continue;
+            }
+            if payment.bank_transaction.is_none() {
+                payment.bank_transaction = transaction_url.clone();
+            }
+            payments.push(payment);
+        }
+
+        Ok(payments)
+    }
+
+    /// Validates and records an invoice payment.
+    pub async fn create(&self, payment: &Payment) -> Result<Value> {
+        payment
+            .validate_for_create()
+            .map_err(|message| ChoSdkError::Validation {
+                resource: "payment".to_string(),
+                errors: vec![message],
+            })?;
+
+        let spec = by_name("bank-transaction-explanations").ok_or_else(|| ChoSdkError::Config {
+            message: "Missing bank-transaction-explanations resource spec".to_string(),
+        })?;
+        let body = serde_json::to_value(payment).map_err(|e| ChoSdkError::Parse {
+            message: format!("Failed to serialize payment: {e}"),
+        })?;
+
+        self.client.resource(spec).create(&body).await
+    }
+}
@@ -0,0 +1,161 @@
+//! Typed accounting report helpers.
+
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::client::{FreeAgentClient, RequestPolicy};
+use crate::error::{ChoSdkError, Result};
+use crate::models::report::{TrialBalanceParams, TrialBalanceSummary};
+
+/// Report endpoint catalog entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ReportMeta {
+    /// Report name accepted by [`ReportsApi::get_by_id`].
+    pub name: &'static str,
+    /// FreeAgent API path relative to the base URL.
+    pub path: &'static str,
+    /// Query parameters FreeAgent documents for the endpoint.
+    pub parameters: &'static [&'static str],
+}
+
+/// Report endpoints known to cho.
+pub const REPORTS: &[ReportMeta] = &[
+    ReportMeta {
+        name: "profit-and-loss",
+        path: "accounting/profit_and_loss/summary",
+        parameters: &["from_date", "to_date", "accounting_period"],
+    },
+    ReportMeta {
+        name: "balance-sheet",
+        path: "accounting/balance_sheet",
+        parameters: &["as_at_date"],
+    },
+    ReportMeta {
+        name: "balance-sheet-opening-balances",
+        path: "accounting/balance_sheet/opening_balances",
+        parameters: &[],
+    },
+    ReportMeta {
+        name: "trial-balance",
+        path: "accounting/trial_balance/summary",
+        parameters: &["from_date", "to_date"],
+    },
+    ReportMeta {
+        name: "trial-balance-opening-balances",
+        path: "accounting/trial_balance/summary/opening_balances",
+        parameters: &[],
+    },
+    ReportMeta {
+        name: "cashflow",
+        path: "cashflow",
+        parameters: &["from_date", "to_date", "months"],
+    },
+];
+
+/// Accounting report API returning parsed reports.
+pub struct ReportsApi<'a> {
+    client: &'a FreeAgentClient,
+    policy: RequestPolicy,
+}
+
+impl<'a> ReportsApi<'a> {
+    /// Creates a new reports API wrapper.
+    pub(crate) fn new(client: &'a FreeAgentClient) -> Self {
+        Self {
+            client,
+            policy: RequestPolicy::default(),
+        }
+    }
+
+    /// Overrides the client timeout for report requests.
+    ///
+    /// Large reports can take far longer than ordinary reads; raising the
+    /// timeout here leaves the global [`crate::config::SdkConfig::timeout`]
+    /// tight for everything else.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.policy.timeout_override = Some(timeout);
+        self
+    }
+
+    /// Report endpoints accepted by [`Self::get_by_id`].
+    pub fn list(&self) -> &'static [ReportMeta] {
+        REPORTS
+    }
+
+    /// Fetches a report by catalog name or `accounting/...` API path.
+    pub async fn get_by_id(&self, report: &str, query: &[(String, String)]) -> Result<Value> {
+        let path = resolve_report_path(report)?;
+        self.client
+            .get_json_with_policy(&path, query, self.policy)
+            .await
+    }
+
+    /// Fetches and parses the trial balance summary for a date range.
+    pub async fn trial_balance(&self, params: &TrialBalanceParams) -> Result<TrialBalanceSummary> {
+        params.validate().map_err(|message| ChoSdkError::Config {
+            message: format!("invalid trial balance range: {message}"),
+        })?;
+
+        let value = self
+            .client
+            .get_json_with_policy(
+                "accounting/trial_balance/summary",
+                &params.query(),
+                self.policy,
+            )
+            .await?;
+        TrialBalanceSummary::from_response(&value)
+    }
+}
+
+fn resolve_report_path(report: &str) -> Result<String> {
+    let trimmed = report.trim().trim_matches('/');
+    if let Some(meta) = REPORTS.iter().find(|meta| meta.name == trimmed) {
+        return Ok(meta.path.to_string());
+    }
+
+    let is_accounting_path = trimmed
+        .strip_prefix("accounting/")
+        .is_some_and(|rest| !rest.is_empty() && !rest.split('/').any(|segment| segment == ".."));
+    if is_accounting_path && !trimmed.contains(['?', '#']) {
+        return Ok(trimmed.to_string());
+    }
+
+    Err(ChoSdkError::Config {
+        message: format!(
+            "Invalid report '{report}'. Use a name from 'cho reports list' or an accounting/... path"
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_report_path_accepts_catalog_names_and_accounting_paths() {
+        assert_eq!(
+            resolve_report_path("trial-balance").expect("catalog name"),
+            "accounting/trial_balance/summary"
+        );
+        assert_eq!(
+            resolve_report_path("/accounting/profit_and_loss/").expect("accounting path"),
+            "accounting/profit_and_loss"
+        );
+    }
+
+    #[test]
+    fn resolve_report_path_rejects_paths_outside_accounting() {
+        for report in [
+            "contacts",
+            "accounting/",
+            "accounting/../users",
+            "accounting/balance_sheet?as_at_date=2026-01-01",
+            "https://api.freeagent.com/v2/accounting/balance_sheet",
+        ] {
+            assert!(resolve_report_path(report).is_err(), "{report} accepted");
+        }
+    }
+}
@@ -1,14 +1,27 @@
 //! Generic FreeAgent resource API.
 
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
 use serde_json::Value;
 
 use crate::client::FreeAgentClient;
-use crate::client::RequestPolicy;
+use crate::client::{PageProgress, RequestPolicy};
 use crate::error::{ChoSdkError, Result};
 use crate::models::{ListResult, Pagination};
 
 use super::specs::ResourceSpec;
 
+/// Decoded resource PDF with the metadata FreeAgent sends alongside it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfDocument {
+    /// Raw PDF bytes.
+    pub content: Vec<u8>,
+    /// Suggested file name, when provided.
+    pub file_name: Option<String>,
+    /// Declared content type, when provided.
+    pub content_type: Option<String>,
+}
+
 /// Generic resource API wrapper.
 pub struct ResourceApi<'a> {
     client: &'a FreeAgentClient,
@@ -36,6 +49,24 @@ impl<'a> ResourceApi<'a> {
             .await
     }
 
+    /// Fetches one list page (1-based); see [`FreeAgentClient::list_page`].
+    pub async fn list_page(
+        &self,
+        query: &[(String, String)],
+        page: u32,
+        per_page: u32,
+    ) -> Result<ListResult> {
+        self.client
+            .list_page(
+                self.spec.path,
+                self.spec.collection_key,
+                query,
+                page,
+                per_page,
+            )
+            .await
+    }
+
     /// Lists resources using query params and pagination settings with policy overrides.
     pub async fn list_with_policy(
         &self,
@@ -54,6 +85,25 @@ impl<'a> ResourceApi<'a> {
             .await
     }
 
+    /// Lists resources, calling `on_page` after each page is collected.
+    pub async fn list_with_progress(
+        &self,
+        query: &[(String, String)],
+        pagination: Pagination,
+        on_page: impl FnMut(&PageProgress),
+    ) -> Result<ListResult> {
+        self.client
+            .list_paginated_with_progress(
+                self.spec.path,
+                self.spec.collection_key,
+                query,
+                pagination,
+                RequestPolicy::default(),
+                on_page,
+            )
+            .await
+    }
+
     /// Gets a single resource by identifier.
     pub async fn get(&self, id: &str) -> Result<Value> {
         self.get_with_policy(id, RequestPolicy::default()).await
@@ -66,6 +116,18 @@ impl<'a> ResourceApi<'a> {
         unwrap_singular(&response, self.spec.singular_key, self.spec.collection_key)
     }
 
+    /// Fetches a resource PDF (`<resource>/<id>/pdf`) and decodes it to bytes.
+    pub async fn get_pdf(&self, id: &str) -> Result<Vec<u8>> {
+        Ok(self.get_pdf_document(id).await?.content)
+    }
+
+    /// Fetches a resource PDF with its file name and content type.
+    pub async fn get_pdf_document(&self, id: &str) -> Result<PdfDocument> {
+        let path = format!("{}/pdf", resource_target_path(self.spec.path, id));
+        let response = self.client.get_json(&path, &[]).await?;
+        decode_pdf_content(&response, self.spec.name)
+    }
+
     /// Creates a resource using request payload.
     pub async fn create(&self, body: &Value) -> Result<Value> {
         let payload = normalize_payload(body, self.spec.singular_key);
@@ -164,6 +226,51 @@ fn unwrap_singular(response: &Value, singular_key: &str, collection_key: &str) -
     })
 }
 
+/// Expands a resource id to the absolute URL FreeAgent expects in filter params.
+pub(crate) fn resource_url(base_url: &str, resource_path: &str, id: &str) -> Result<String> {
+    let trimmed = id.trim().trim_end_matches('/');
+    if trimmed.is_empty() {
+        return Err(ChoSdkError::Config {
+            message: format!("Invalid {resource_path} filter: identifier is empty"),
+        });
+    }
+
+    if trimmed.starts_with("https://") || trimmed.starts_with("http://") {
+        return Ok(trimmed.to_string());
+    }
+
+    Ok(format!(
+        "{}/{}",
+        base_url.trim_end_matches('/'),
+        resource_target_path(resource_path, trimmed)
+    ))
+}
+
+fn decode_pdf_content(response: &Value, resource: &str) -> Result<PdfDocument> {
+    let pdf = response.get("pdf").unwrap_or(response);
+    let encoded = pdf
+        .get("content")
+        .and_then(Value::as_str)
+        .ok_or_else(|| ChoSdkError::Parse {
+            message: format!(
+                "Expected {resource} PDF response to contain pdf.content; drafts may not render a PDF"
+            ),
+        })?;
+
+    let content = BASE64_STANDARD
+        .decode(encoded)
+        .map_err(|e| ChoSdkError::Parse {
+            message: format!("Invalid base64 in {resource} PDF response: {e}"),
+        })?;
+    let text = |key: &str| pdf.get(key).and_then(Value::as_str).map(str::to_string);
+
+    Ok(PdfDocument {
+        content,
+        file_name: text("file_name"),
+        content_type: text("content_type"),
+    })
+}
+
 fn encode_path_segment(id: &str) -> String {
     url::form_urlencoded::byte_serialize(id.as_bytes()).collect()
 }
@@ -192,6 +299,40 @@ mod tests {
         assert_eq!(path, "https://api.freeagent.com/v2/contacts/123");
     }
 
+    #[test]
+    fn decode_pdf_content_reads_wrapped_base64_and_rejects_missing_content() {
+        let response = serde_json::json!({
+            "pdf": { "content": "JVBERi0xLjc=", "file_name": "estimate-7.pdf" }
+        });
+        assert_eq!(
+            decode_pdf_content(&response, "estimates").expect("pdf document"),
+            PdfDocument {
+                content: b"%PDF-1.7".to_vec(),
+                file_name: Some("estimate-7.pdf".to_string()),
+                content_type: None,
+            }
+        );
+
+        let err = decode_pdf_content(&serde_json::json!({ "pdf": {} }), "estimates")
+            .expect_err("missing content should fail");
+        assert!(err.to_string().contains("estimates PDF"));
+    }
+
+    #[test]
+    fn resource_url_expands_ids_against_base_url_and_keeps_urls() {
+        let base = "https://api.freeagent.com/v2/";
+        assert_eq!(
+            resource_url(base, "bank_accounts", "42").expect("id"),
+            "https://api.freeagent.com/v2/bank_accounts/42"
+        );
+        assert_eq!(
+            resource_url(base, "contacts", "https://api.freeagent.com/v2/contacts/7/")
+                .expect("url"),
+            "https://api.freeagent.com/v2/contacts/7"
+        );
+        assert!(resource_url(base, "contacts", "  ").is_err());
+    }
+
     #[test]
     fn resource_target_path_encodes_relative_ids() {
         let path = resource_target_path("contacts", "abc/123");
@@ -0,0 +1,40 @@
+//! Sales tax period API.
+
+use serde_json::Value;
+
+use crate::client::FreeAgentClient;
+use crate::error::{ChoSdkError, Result};
+use crate::models::sales_tax::SalesTaxPeriod;
+
+use super::specs::by_name;
+
+/// Sales tax period helpers with local validation.
+pub struct SalesTaxPeriodsApi<'a> {
+    client: &'a FreeAgentClient,
+}
+
+impl<'a> SalesTaxPeriodsApi<'a> {
+    /// Creates a new sales tax period API wrapper.
+    pub(crate) fn new(client: &'a FreeAgentClient) -> Self {
+        Self { client }
+    }
+
+    /// Validates and creates a sales tax period.
+    pub async fn create(&self, period: &SalesTaxPeriod) -> Result<Value> {
+        period
+            .validate_for_create()
+            .map_err(|message| ChoSdkError::Validation {
+                resource: "sales tax period".to_string(),
+                errors: vec![message],
+            })?;
+
+        let spec = by_name("sales-tax-periods").ok_or_else(|| ChoSdkError::Config {
+            message: "Missing sales-tax-periods resource spec".to_string(),
+        })?;
+        let body = serde_json::to_value(period).map_err(|e| ChoSdkError::Parse {
+            message: format!("Failed to serialize sales tax period: {e}"),
+        })?;
+
+        self.client.resource(spec).create(&body).await
+    }
+}
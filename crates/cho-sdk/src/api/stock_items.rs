@@ -0,0 +1,53 @@
+//! Typed stock item helpers.
+
+use crate::client::FreeAgentClient;
+use crate::error::{ChoSdkError, Result};
+use crate::models::stock_item::StockItem;
+use crate::models::{ListResult, Pagination};
+
+use super::specs::by_name;
+
+/// Stock item API returning typed items.
+pub struct StockItemsApi<'a> {
+    client: &'a FreeAgentClient,
+}
+
+impl<'a> StockItemsApi<'a> {
+    /// Creates a new stock item API wrapper.
+    pub(crate) fn new(client: &'a FreeAgentClient) -> Self {
+        Self { client }
+    }
+
+    /// Lists stock items with quantity on hand.
+    pub async fn list(&self, pagination: Pagination) -> Result<ListResult<StockItem>> {
+        let spec = by_name("stock-items").ok_or_else(|| ChoSdkError::Config {
+            message: "Missing stock-items resource spec".to_string(),
+        })?;
+        let result = self.client.resource(spec).list(&[], pagination).await?;
+
+        let mut items = Vec::with_capacity(result.len());
+        for item in &result {
+            items.push(
+                serde_json::from_value(item.clone()).map_err(|e| ChoSdkError::Parse {
+                    message: format!("Failed to parse stock item: {e}"),
+                })?,
+            );
+        }
+        Ok(ListResult {
+            items,
+            total: result.total,
+            has_more: result.has_more,
+            page: result.page,
+            per_page: result.per_page,
+        })
+    }
+
+    /// Lists every stock item at or below `threshold` units on hand.
+    pub async fn list_low_stock(&self, threshold: f64) -> Result<Vec<StockItem>> {
+        let result = self.list(Pagination::all()).await?;
+        Ok(result
+            .into_iter()
+            .filter(|item| item.is_low_stock(threshold))
+            .collect())
+    }
+}
@@ -4,17 +4,24 @@ pub mod oauth;
 pub mod storage;
 pub mod token;
 
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 
 use secrecy::{ExposeSecret, SecretString};
 use tokio::sync::{Mutex, RwLock};
+use tracing::warn;
 
+use crate::client::backoff_delay;
 use crate::config::SdkConfig;
 use crate::error::{ChoSdkError, Result};
 use crate::models::TokenStatus;
 
 use self::token::{TokenPair, TokenResponse};
 
+/// Retry cap for token endpoint network failures, applied below `max_retries`.
+const TOKEN_MAX_RETRIES: u32 = 2;
+
 /// Login flow output details.
 #[derive(Debug, Clone)]
 pub struct LoginResult {
@@ -79,7 +86,7 @@ impl AuthManager {
 
     /// Loads cached tokens from storage.
     pub async fn load_stored_tokens(&self) -> Result<bool> {
-        if let Some(stored) = storage::load_tokens()? {
+        if let Some(stored) = storage::load_tokens_from(&self.token_path()?)? {
             let pair = TokenPair::from_stored(&stored);
             let mut guard = self.token.write().await;
             *guard = Some(pair);
@@ -91,7 +98,7 @@ impl AuthManager {
 
     /// Clears tokens from memory and storage.
     pub async fn logout(&self) -> Result<()> {
-        storage::clear_tokens()?;
+        storage::clear_tokens_at(&self.token_path()?)?;
         let mut guard = self.token.write().await;
         *guard = None;
         Ok(())
@@ -195,23 +202,30 @@ impl AuthManager {
         redirect_uri: &str,
     ) -> Result<TokenResponse> {
         let response = self
-            .http_client
-            .post(&self.config.token_url)
-            .basic_auth(&self.client_id, Some(self.client_secret.expose_secret()))
-            .form(&[
+            .post_token_form(&[
                 ("grant_type", "authorization_code"),
                 ("code", code),
                 ("redirect_uri", redirect_uri),
             ])
-            .send()
-            .await
-            .map_err(ChoSdkError::Network)?;
+            .await?;
 
-        parse_token_response(response).await
+        parse_token_response(response, TokenGrant::AuthorizationCode).await
     }
 
     /// Refreshes tokens using current refresh token.
     pub async fn refresh(&self) -> Result<()> {
+        self.refresh_with(false).await
+    }
+
+    /// Refreshes tokens even when the stored refresh token looks expired locally.
+    ///
+    /// The server remains the authority: a revoked token still fails with
+    /// [`ChoSdkError::TokenExpired`].
+    pub async fn force_refresh(&self) -> Result<()> {
+        self.refresh_with(true).await
+    }
+
+    async fn refresh_with(&self, force: bool) -> Result<()> {
         let _guard = self.refresh_lock.lock().await;
 
         let refresh_token = {
@@ -219,32 +233,77 @@ impl AuthManager {
             let pair = guard.as_ref().ok_or_else(|| ChoSdkError::AuthRequired {
                 message: "No token available, run 'cho auth login'".to_string(),
             })?;
-            if !pair.can_refresh() {
+            let refresh_token = pair
+                .refresh_token()
+                .filter(|token| !token.is_empty())
+                .ok_or_else(|| ChoSdkError::TokenExpired {
+                    message: "No refresh token stored, run 'cho auth login'".to_string(),
+                })?;
+            if !force && !pair.can_refresh() {
                 return Err(ChoSdkError::TokenExpired {
-                    message: "Refresh token is unavailable or expired, run 'cho auth login'"
-                        .to_string(),
+                    message: "Refresh token has expired, run 'cho auth login'".to_string(),
                 });
             }
-            pair.refresh_token().unwrap_or_default().to_string()
+            refresh_token.to_string()
         };
 
         let response = self
-            .http_client
-            .post(&self.config.token_url)
-            .basic_auth(&self.client_id, Some(self.client_secret.expose_secret()))
-            .form(&[
+            .post_token_form(&[
                 ("grant_type", "refresh_token"),
                 ("refresh_token", refresh_token.as_str()),
             ])
-            .send()
-            .await
-            .map_err(ChoSdkError::Network)?;
+            .await?;
 
-        let token_response = parse_token_response(response).await?;
+        let token_response = parse_token_response(response, TokenGrant::RefreshToken).await?;
         let pair = TokenPair::from_response(&token_response);
         self.store_pair(pair).await
     }
 
+    /// Posts a token endpoint form, retrying connect and timeout failures.
+    ///
+    /// Uses the API backoff schedule and retry budget with at most
+    /// [`TOKEN_MAX_RETRIES`] retries. HTTP error responses are returned
+    /// as-is for [`parse_token_response`] to classify.
+    async fn post_token_form(&self, form: &[(&str, &str)]) -> Result<reqwest::Response> {
+        let max_retries = self.config.max_retries.min(TOKEN_MAX_RETRIES);
+        let retry_started = Instant::now();
+        let mut attempt = 0;
+
+        loop {
+            let result = self
+                .http_client
+                .post(&self.config.token_url)
+                .basic_auth(&self.client_id, Some(self.client_secret.expose_secret()))
+                .form(form)
+                .send()
+                .await;
+
+            let err = match result {
+                Ok(response) => return Ok(response),
+                Err(err) => err,
+            };
+
+            let delay = backoff_delay(attempt);
+            let within_budget = self
+                .config
+                .max_retry_duration
+                .is_none_or(|budget| retry_started.elapsed() + delay <= budget);
+            if attempt < max_retries && (err.is_connect() || err.is_timeout()) && within_budget {
+                warn!(
+                    attempt = attempt + 1,
+                    max_attempts = max_retries + 1,
+                    delay_ms = delay.as_millis() as u64,
+                    "token endpoint network error, retrying"
+                );
+                attempt += 1;
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+
+            return Err(ChoSdkError::Network(err));
+        }
+    }
+
     /// Returns a valid access token, refreshing when required.
     pub async fn get_access_token(&self) -> Result<String> {
         {
@@ -266,9 +325,16 @@ impl AuthManager {
         Ok(pair.access_token().to_string())
     }
 
+    fn token_path(&self) -> Result<PathBuf> {
+        match &self.config.token_file {
+            Some(path) => Ok(path.clone()),
+            None => crate::home::token_path(),
+        }
+    }
+
     async fn store_pair(&self, pair: TokenPair) -> Result<()> {
         if self.persist_tokens {
-            storage::store_tokens(&pair.to_stored())?;
+            storage::store_tokens_to(&self.token_path()?, &pair.to_stored())?;
         }
         let mut guard = self.token.write().await;
         *guard = Some(pair);
@@ -288,10 +354,49 @@ impl std::fmt::Debug for AuthManager {
     }
 }
 
-async fn parse_token_response(response: reqwest::Response) -> Result<TokenResponse> {
+/// Returns the server's description when an OAuth error body reports `invalid_grant`.
+fn invalid_grant_detail(body: &str) -> Option<String> {
+    let value = serde_json::from_str::<serde_json::Value>(body).ok()?;
+    if value.get("error").and_then(serde_json::Value::as_str) != Some("invalid_grant") {
+        return None;
+    }
+
+    Some(
+        value
+            .get("error_description")
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .filter(|detail| !detail.is_empty())
+            .unwrap_or("no description")
+            .to_string(),
+    )
+}
+
+/// Grant sent to the token endpoint, which decides how `invalid_grant` reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenGrant {
+    AuthorizationCode,
+    RefreshToken,
+}
+
+async fn parse_token_response(
+    response: reqwest::Response,
+    grant: TokenGrant,
+) -> Result<TokenResponse> {
     if !response.status().is_success() {
         let status = response.status();
         let body = response.text().await.unwrap_or_default();
+        // A rejected authorization code is a login problem, not a revoked
+        // session, so it falls through to the generic status mapping.
+        if grant == TokenGrant::RefreshToken
+            && let Some(detail) = invalid_grant_detail(&body)
+        {
+            return Err(ChoSdkError::TokenExpired {
+                message: format!(
+                    "Token endpoint rejected the grant (invalid_grant: {detail}); the refresh token was revoked or expired, run 'cho auth login'"
+                ),
+            });
+        }
         return Err(match status {
             reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
                 ChoSdkError::AuthRequired {
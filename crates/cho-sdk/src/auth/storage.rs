@@ -1,6 +1,7 @@
 //! Token storage helpers.
 
 use std::io::Write;
+use std::path::Path;
 
 use crate::error::{ChoSdkError, Result};
 use crate::home;
@@ -9,26 +10,26 @@ use super::token::StoredTokens;
 
 /// Loads stored tokens from file storage.
 pub fn load_tokens() -> Result<Option<StoredTokens>> {
-    load_from_file()
+    load_tokens_from(&home::token_path()?)
 }
 
 /// Stores tokens in file storage.
 pub fn store_tokens(tokens: &StoredTokens) -> Result<()> {
-    store_to_file(tokens)
+    store_tokens_to(&home::token_path()?, tokens)
 }
 
 /// Clears stored tokens from file storage.
 pub fn clear_tokens() -> Result<()> {
-    clear_file()
+    clear_tokens_at(&home::token_path()?)
 }
 
-fn load_from_file() -> Result<Option<StoredTokens>> {
-    let path = home::token_path()?;
+/// Loads stored tokens from an explicit token file.
+pub fn load_tokens_from(path: &Path) -> Result<Option<StoredTokens>> {
     if !path.exists() {
         return Ok(None);
     }
 
-    let raw = std::fs::read_to_string(&path).map_err(|e| ChoSdkError::Config {
+    let raw = std::fs::read_to_string(path).map_err(|e| ChoSdkError::Config {
         message: format!("Failed reading token file {}: {e}", path.display()),
     })?;
 
@@ -39,8 +40,8 @@ fn load_from_file() -> Result<Option<StoredTokens>> {
     Ok(Some(tokens))
 }
 
-fn store_to_file(tokens: &StoredTokens) -> Result<()> {
-    let path = home::token_path()?;
+/// Stores tokens in an explicit token file.
+pub fn store_tokens_to(path: &Path, tokens: &StoredTokens) -> Result<()> {
     let raw = serde_json::to_string(tokens).map_err(|e| ChoSdkError::Config {
         message: format!("Failed serializing tokens for file storage: {e}"),
     })?;
@@ -49,7 +50,7 @@ fn store_to_file(tokens: &StoredTokens) -> Result<()> {
         .create(true)
         .write(true)
         .truncate(true)
-        .open(&path)
+        .open(path)
         .map_err(|e| ChoSdkError::Config {
             message: format!("Failed opening token file {}: {e}", path.display()),
         })?;
@@ -58,7 +59,7 @@ fn store_to_file(tokens: &StoredTokens) -> Result<()> {
     {
         use std::os::unix::fs::PermissionsExt;
 
-        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).map_err(|e| {
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600)).map_err(|e| {
             ChoSdkError::Config {
                 message: format!(
                     "Failed setting secure permissions on {}: {e}",
@@ -74,10 +75,10 @@ fn store_to_file(tokens: &StoredTokens) -> Result<()> {
         })
 }
 
-fn clear_file() -> Result<()> {
-    let path = home::token_path()?;
+/// Clears an explicit token file.
+pub fn clear_tokens_at(path: &Path) -> Result<()> {
     if path.exists() {
-        std::fs::remove_file(&path).map_err(|e| ChoSdkError::Config {
+        std::fs::remove_file(path).map_err(|e| ChoSdkError::Config {
             message: format!("Failed deleting token file {}: {e}", path.display()),
         })?;
     }
@@ -1,9 +1,13 @@
 //! Blocking wrapper for [`crate::client::FreeAgentClient`].
 
+use chrono::NaiveDate;
+
 use crate::api::ResourceSpec;
 use crate::client::FreeAgentClient;
 use crate::error::{ChoSdkError, Result};
-use crate::models::{ListResult, Pagination};
+use crate::models::{
+    CreditNoteEmail, InvoiceStatus, ListResult, Pagination, Payment, SalesTaxPeriod,
+};
 
 /// Synchronous client wrapper.
 pub struct BlockingClient {
@@ -43,4 +47,84 @@ impl BlockingClient {
     pub fn get(&self, spec: ResourceSpec, id: &str) -> Result<serde_json::Value> {
         self.runtime.block_on(self.inner.resource(spec).get(id))
     }
+
+    /// Fetches a resource PDF synchronously.
+    pub fn get_pdf(&self, spec: ResourceSpec, id: &str) -> Result<Vec<u8>> {
+        self.runtime.block_on(self.inner.resource(spec).get_pdf(id))
+    }
+
+    /// Lists transactions for one bank account synchronously.
+    pub fn list_bank_transactions_for_account(
+        &self,
+        bank_account: &str,
+        pagination: Pagination,
+    ) -> Result<ListResult> {
+        self.runtime.block_on(
+            self.inner
+                .bank_transactions()
+                .list_for_account(bank_account, pagination),
+        )
+    }
+
+    /// Lists invoices in one status view synchronously.
+    pub fn list_invoices_by_status(
+        &self,
+        status: InvoiceStatus,
+        pagination: Pagination,
+    ) -> Result<ListResult> {
+        self.runtime
+            .block_on(self.inner.invoices().list_by_status(status, pagination))
+    }
+
+    /// Lists invoices for one contact synchronously.
+    pub fn list_invoices_for_contact(
+        &self,
+        contact: &str,
+        pagination: Pagination,
+    ) -> Result<ListResult> {
+        self.runtime
+            .block_on(self.inner.invoices().list_for_contact(contact, pagination))
+    }
+
+    /// Lists invoices within a date range synchronously.
+    pub fn list_invoices_in_date_range(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        pagination: Pagination,
+    ) -> Result<ListResult> {
+        self.runtime.block_on(
+            self.inner
+                .invoices()
+                .list_in_date_range(from, to, pagination),
+        )
+    }
+
+    /// Marks an invoice as sent synchronously.
+    pub fn mark_invoice_sent(&self, id: &str) -> Result<serde_json::Value> {
+        self.runtime.block_on(self.inner.invoices().mark_sent(id))
+    }
+
+    /// Fetches a credit note PDF synchronously.
+    pub fn get_credit_note_pdf(&self, id: &str) -> Result<Vec<u8>> {
+        self.runtime.block_on(self.inner.credit_notes().get_pdf(id))
+    }
+
+    /// Emails a credit note synchronously.
+    pub fn email_credit_note(&self, id: &str, email: &CreditNoteEmail) -> Result<()> {
+        self.runtime
+            .block_on(self.inner.credit_notes().email(id, email))
+    }
+
+    /// Lists invoice payments explaining one bank transaction synchronously.
+    pub fn payments_for_batch(&self, bank_transaction: &str) -> Result<Vec<Payment>> {
+        self.runtime
+            .block_on(self.inner.payments().for_batch(bank_transaction))
+    }
+
+    /// Validates and creates a sales tax period synchronously.
+    pub fn create_sales_tax_period(&self, period: &SalesTaxPeriod) -> Result<serde_json::Value> {
+        self.runtime
+            .block_on(self.inner.sales_tax_periods().create(period))
+    }
 }
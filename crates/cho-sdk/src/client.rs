@@ -1,6 +1,6 @@
 //! FreeAgent API client.
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use std::time::Instant;
 
@@ -8,13 +8,22 @@ use serde_json::Value;
 use tracing::{debug, warn};
 use url::Url;
 
+use crate::api::accounting_transactions::AccountingTransactionsApi;
+use crate::api::bank_transactions::BankTransactionsApi;
+use crate::api::contacts::ContactsApi;
+use crate::api::credit_notes::CreditNotesApi;
+use crate::api::invoices::InvoicesApi;
+use crate::api::payments::PaymentsApi;
+use crate::api::reports::ReportsApi;
 use crate::api::resource::ResourceApi;
+use crate::api::sales_tax_periods::SalesTaxPeriodsApi;
 use crate::api::specs::ResourceSpec;
+use crate::api::stock_items::StockItemsApi;
 use crate::auth::AuthManager;
 use crate::config::SdkConfig;
 use crate::error::{ChoSdkError, Result};
 use crate::liabilities::LiabilitiesService;
-use crate::models::{ListResult, Pagination, SessionStatus};
+use crate::models::{ListResult, Pagination, RateLimitSnapshot, SessionStatus};
 
 /// Observer for low-level HTTP events.
 pub trait HttpObserver: Send + Sync {
@@ -22,6 +31,23 @@ pub trait HttpObserver: Send + Sync {
     fn on_request(&self, event: &HttpRequestEvent) -> Result<()>;
     /// Called after a response is received (or request fails).
     fn on_response(&self, event: &HttpResponseEvent) -> Result<()>;
+    /// Called after each page of a multi-page listing is collected.
+    fn on_page(&self, _progress: &PageProgress) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Progress of a multi-page listing after one page.
+#[derive(Debug, Clone)]
+pub struct PageProgress {
+    /// Collection key being listed (`invoices`, `contacts`, ...).
+    pub collection: String,
+    /// Pages fetched so far.
+    pub pages_fetched: u32,
+    /// Items collected so far, after any limit truncation.
+    pub items_so_far: usize,
+    /// Total from `X-Total-Count` when FreeAgent reported one.
+    pub total: Option<usize>,
 }
 
 /// HTTP request event.
@@ -37,6 +63,10 @@ pub struct HttpRequestEvent {
     pub has_body: bool,
     /// True when request is mutating.
     pub mutating: bool,
+    /// URL including the encoded query string, exactly as sent.
+    pub full_url: String,
+    /// Request headers as sent, with credentials redacted.
+    pub headers: Vec<(String, String)>,
 }
 
 /// HTTP response event.
@@ -66,11 +96,18 @@ pub struct RequestPolicy {
 }
 
 /// Main FreeAgent API client.
+///
+/// Cloning is cheap and yields another handle onto the same auth manager,
+/// connection pool, and rate-limit snapshot, so clones draw on one shared
+/// FreeAgent request budget rather than each getting their own.
+#[derive(Clone)]
 pub struct FreeAgentClient {
     config: SdkConfig,
     auth: Arc<AuthManager>,
     http_client: reqwest::Client,
     observer: Option<Arc<dyn HttpObserver>>,
+    rate_limit: Arc<Mutex<Option<RateLimitSnapshot>>>,
+    default_headers: reqwest::header::HeaderMap,
 }
 
 impl FreeAgentClient {
@@ -89,11 +126,80 @@ impl FreeAgentClient {
         &self.auth
     }
 
+    /// Returns rate-limit quota from the latest response that reported it.
+    pub fn rate_limit_snapshot(&self) -> Option<RateLimitSnapshot> {
+        self.rate_limit
+            .lock()
+            .map(|snapshot| *snapshot)
+            .unwrap_or_default()
+    }
+
+    /// Fails with [`ChoSdkError::WriteNotAllowed`] unless the config allows writes.
+    ///
+    /// Every mutating request passes through this check, so callers only need
+    /// it to fail before doing local work such as reading payload files.
+    pub fn ensure_writes_allowed(&self) -> Result<()> {
+        if self.config.allow_writes {
+            Ok(())
+        } else {
+            Err(ChoSdkError::WriteNotAllowed {
+                message:
+                    "Set [safety] allow_writes = true in config.toml to enable mutating commands"
+                        .to_string(),
+            })
+        }
+    }
+
     /// Returns generic resource API wrapper for a spec.
     pub fn resource(&self, spec: ResourceSpec) -> ResourceApi<'_> {
         ResourceApi::new(self, spec)
     }
 
+    /// Returns accounting transaction ledger export helpers.
+    pub fn accounting_transactions(&self) -> AccountingTransactionsApi<'_> {
+        AccountingTransactionsApi::new(self)
+    }
+
+    /// Returns bank-account scoped transaction helpers.
+    pub fn bank_transactions(&self) -> BankTransactionsApi<'_> {
+        BankTransactionsApi::new(self)
+    }
+
+    /// Returns contact helpers.
+    pub fn contacts(&self) -> ContactsApi<'_> {
+        ContactsApi::new(self)
+    }
+
+    /// Returns credit note PDF and email helpers.
+    pub fn credit_notes(&self) -> CreditNotesApi<'_> {
+        CreditNotesApi::new(self)
+    }
+
+    /// Returns typed invoice list helpers.
+    pub fn invoices(&self) -> InvoicesApi<'_> {
+        InvoicesApi::new(self)
+    }
+
+    /// Returns invoice payment helpers.
+    pub fn payments(&self) -> PaymentsApi<'_> {
+        PaymentsApi::new(self)
+    }
+
+    /// Returns parsed accounting report helpers.
+    pub fn reports(&self) -> ReportsApi<'_> {
+        ReportsApi::new(self)
+    }
+
+    /// Returns validated sales tax period helpers.
+    pub fn sales_tax_periods(&self) -> SalesTaxPeriodsApi<'_> {
+        SalesTaxPeriodsApi::new(self)
+    }
+
+    /// Returns typed stock item helpers.
+    pub fn stock_items(&self) -> StockItemsApi<'_> {
+        StockItemsApi::new(self)
+    }
+
     /// Returns finance/status helpers for liability and reconciliation workflows.
     pub fn liabilities(&self) -> LiabilitiesService<'_> {
         LiabilitiesService::new(self)
@@ -160,6 +266,10 @@ impl FreeAgentClient {
     }
 
     /// Fetches a singleton resource/object.
+    ///
+    /// Also the escape hatch for endpoints the SDK does not model yet: it
+    /// returns untyped JSON with the same auth, rate-limit, and retry handling,
+    /// and `path` must still resolve under the configured base URL.
     pub async fn get_json(&self, path: &str, query: &[(String, String)]) -> Result<Value> {
         self.get_json_with_policy(path, query, RequestPolicy::default())
             .await
@@ -198,6 +308,9 @@ impl FreeAgentClient {
     }
 
     /// Sends POST JSON.
+    ///
+    /// Pairs with [`Self::get_json`] for unmodelled endpoints; pass
+    /// `mutating: true` for anything that writes so `allow_writes` applies.
     pub async fn post_json(&self, path: &str, body: &Value, mutating: bool) -> Result<Value> {
         let response = self
             .request(
@@ -212,6 +325,48 @@ impl FreeAgentClient {
         Ok(response.body)
     }
 
+    /// Sends POST JSON with request policy overrides.
+    pub async fn post_json_with_policy(
+        &self,
+        path: &str,
+        body: &Value,
+        mutating: bool,
+        policy: RequestPolicy,
+    ) -> Result<Value> {
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                path,
+                &[],
+                Some(body),
+                mutating,
+                policy,
+            )
+            .await?;
+        Ok(response.body)
+    }
+
+    /// Sends a POST request with query parameters.
+    pub async fn post_json_with_query(
+        &self,
+        path: &str,
+        query: &[(String, String)],
+        body: &Value,
+        mutating: bool,
+    ) -> Result<Value> {
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                path,
+                query,
+                Some(body),
+                mutating,
+                RequestPolicy::default(),
+            )
+            .await?;
+        Ok(response.body)
+    }
+
     /// Sends PUT JSON.
     pub async fn put_json(&self, path: &str, body: &Value, mutating: bool) -> Result<Value> {
         let response = self
@@ -268,6 +423,23 @@ impl FreeAgentClient {
         query: &[(String, String)],
         pagination: Pagination,
         policy: RequestPolicy,
+    ) -> Result<ListResult> {
+        self.list_paginated_with_progress(path, collection_key, query, pagination, policy, |_| {})
+            .await
+    }
+
+    /// Fetches list pages, calling `on_page` after each page is collected.
+    ///
+    /// The client observer's [`HttpObserver::on_page`] sees the same
+    /// progress, so an observer can report it without threading a callback.
+    pub async fn list_paginated_with_progress(
+        &self,
+        path: &str,
+        collection_key: &str,
+        query: &[(String, String)],
+        pagination: Pagination,
+        policy: RequestPolicy,
+        mut on_page: impl FnMut(&PageProgress),
     ) -> Result<ListResult> {
         let per_page = pagination.per_page.clamp(1, 100);
         let mut page: u32 = 1;
@@ -276,34 +448,38 @@ impl FreeAgentClient {
         let mut has_more;
 
         loop {
-            let mut page_query = query.to_vec();
-            page_query.push(("page".to_string(), page.to_string()));
-            page_query.push(("per_page".to_string(), per_page.to_string()));
-
-            let response = self
-                .request(reqwest::Method::GET, path, &page_query, None, false, policy)
+            let fetched = self
+                .fetch_page(path, collection_key, query, page, per_page, policy)
                 .await?;
-
             if total.is_none() {
-                total = response
-                    .headers
-                    .get("X-Total-Count")
-                    .and_then(|value| value.to_str().ok())
-                    .and_then(|raw| raw.parse::<usize>().ok());
+                total = fetched.total;
             }
 
-            let page_items = extract_collection(&response.body, collection_key)?;
-            let count_before = items.len();
-            items.extend(page_items);
-
-            if !pagination.all && pagination.limit > 0 && items.len() >= pagination.limit {
+            let added = fetched.items.len();
+            items.extend(fetched.items);
+            let truncated =
+                !pagination.all && pagination.limit > 0 && items.len() >= pagination.limit;
+            if truncated {
                 items.truncate(pagination.limit);
+            }
+
+            let progress = PageProgress {
+                collection: collection_key.to_string(),
+                pages_fetched: page,
+                items_so_far: items.len(),
+                total,
+            };
+            if let Some(observer) = &self.observer {
+                observer.on_page(&progress)?;
+            }
+            on_page(&progress);
+
+            if truncated {
                 has_more = true;
                 break;
             }
 
-            let added = items.len() - count_before;
-            has_more = response_has_next_link(&response.headers);
+            has_more = fetched.has_more;
 
             if added == 0 || !has_more {
                 break;
@@ -321,6 +497,63 @@ impl FreeAgentClient {
         })
     }
 
+    /// Fetches exactly one list page (1-based) for caller-driven paging.
+    ///
+    /// Pair with [`ListResult::next_page`] to walk pages one request at a
+    /// time, e.g. to report progress between pages.
+    pub async fn list_page(
+        &self,
+        path: &str,
+        collection_key: &str,
+        query: &[(String, String)],
+        page: u32,
+        per_page: u32,
+    ) -> Result<ListResult> {
+        let page = page.max(1);
+        let per_page = per_page.clamp(1, 100);
+        self.fetch_page(
+            path,
+            collection_key,
+            query,
+            page,
+            per_page,
+            RequestPolicy::default(),
+        )
+        .await
+    }
+
+    async fn fetch_page(
+        &self,
+        path: &str,
+        collection_key: &str,
+        query: &[(String, String)],
+        page: u32,
+        per_page: u32,
+        policy: RequestPolicy,
+    ) -> Result<ListResult> {
+        let mut page_query = query.to_vec();
+        page_query.push(("page".to_string(), page.to_string()));
+        page_query.push(("per_page".to_string(), per_page.to_string()));
+
+        let response = self
+            .request(reqwest::Method::GET, path, &page_query, None, false, policy)
+            .await?;
+
+        let total = response
+            .headers
+            .get("X-Total-Count")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|raw| raw.parse::<usize>().ok());
+
+        Ok(ListResult {
+            items: extract_collection(&response.body, collection_key)?,
+            total,
+            has_more: has_more_pages(&response.headers),
+            page,
+            per_page,
+        })
+    }
+
     async fn request(
         &self,
         method: reqwest::Method,
@@ -330,12 +563,8 @@ impl FreeAgentClient {
         mutating: bool,
         policy: RequestPolicy,
     ) -> Result<RawResponse> {
-        if mutating && !self.config.allow_writes {
-            return Err(ChoSdkError::WriteNotAllowed {
-                message:
-                    "Set [safety] allow_writes = true in config.toml to enable mutating commands"
-                        .to_string(),
-            });
+        if mutating {
+            self.ensure_writes_allowed()?;
         }
 
         let max_retries = policy
@@ -343,6 +572,7 @@ impl FreeAgentClient {
             .unwrap_or(self.config.max_retries);
         let url = build_url(&self.config.base_url, path)?;
         let mut did_refresh = false;
+        let retry_started = Instant::now();
 
         let mut attempt: u32 = 0;
 
@@ -350,22 +580,13 @@ impl FreeAgentClient {
             let started = Instant::now();
             let access_token = self.auth.get_access_token().await?;
 
-            if let Some(observer) = &self.observer {
-                observer.on_request(&HttpRequestEvent {
-                    method: method.as_str().to_string(),
-                    url: url.clone(),
-                    query: query.to_vec(),
-                    has_body: body.is_some(),
-                    mutating,
-                })?;
-            }
-
             let mut request = self
                 .http_client
                 .request(method.clone(), &url)
                 .header(reqwest::header::ACCEPT, "application/json")
                 .header(reqwest::header::CONTENT_TYPE, "application/json")
                 .header(reqwest::header::USER_AGENT, &self.config.user_agent)
+                .headers(self.default_headers.clone())
                 .bearer_auth(access_token)
                 .query(query);
 
@@ -377,7 +598,21 @@ impl FreeAgentClient {
                 request = request.json(payload);
             }
 
-            let result = request.send().await;
+            let request = request.build().map_err(ChoSdkError::Network)?;
+
+            if let Some(observer) = &self.observer {
+                observer.on_request(&HttpRequestEvent {
+                    method: method.as_str().to_string(),
+                    url: url.clone(),
+                    query: query.to_vec(),
+                    has_body: body.is_some(),
+                    mutating,
+                    full_url: request.url().to_string(),
+                    headers: redacted_headers(request.headers()),
+                })?;
+            }
+
+            let result = self.http_client.execute(request).await;
             let elapsed_ms = started.elapsed().as_millis() as u64;
 
             let response = match result {
@@ -394,8 +629,11 @@ impl FreeAgentClient {
                         })?;
                     }
 
-                    if attempt < max_retries && (err.is_connect() || err.is_timeout()) {
-                        let delay = backoff_delay(attempt);
+                    let delay = backoff_delay(attempt);
+                    if attempt < max_retries
+                        && (err.is_connect() || err.is_timeout())
+                        && self.retry_budget_allows(retry_started, delay)
+                    {
                         warn!(
                             attempt = attempt + 1,
                             max_attempts = max_retries + 1,
@@ -413,6 +651,7 @@ impl FreeAgentClient {
 
             let status = response.status();
             let headers = response.headers().clone();
+            self.record_rate_limit(&headers, status);
             let retry_after = response
                 .headers()
                 .get("Retry-After")
@@ -443,9 +682,10 @@ impl FreeAgentClient {
 
             if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
                 let wait = retry_after.unwrap_or(60);
-                if attempt < max_retries {
+                let delay = self.retry_after_delay(wait);
+                if attempt < max_retries && self.retry_budget_allows(retry_started, delay) {
                     attempt += 1;
-                    tokio::time::sleep(std::time::Duration::from_secs(wait)).await;
+                    tokio::time::sleep(delay).await;
                     continue;
                 }
                 return Err(ChoSdkError::RateLimited { retry_after: wait });
@@ -453,6 +693,10 @@ impl FreeAgentClient {
 
             let text = response.text().await.map_err(ChoSdkError::Network)?;
 
+            if status == reqwest::StatusCode::FORBIDDEN {
+                return Err(ChoSdkError::forbidden(&text));
+            }
+
             if status == reqwest::StatusCode::NOT_FOUND {
                 return Err(ChoSdkError::NotFound {
                     resource: path.to_string(),
@@ -478,6 +722,36 @@ impl FreeAgentClient {
         }
     }
 
+    fn record_rate_limit(&self, headers: &reqwest::header::HeaderMap, status: reqwest::StatusCode) {
+        if let Some(snapshot) = rate_limit_from_headers(headers, status)
+            && let Ok(mut current) = self.rate_limit.lock()
+        {
+            *current = Some(snapshot);
+        }
+    }
+
+    fn retry_after_delay(&self, wait_secs: u64) -> Duration {
+        let delay = clamp_retry_after(
+            wait_secs,
+            self.config.min_retry_after,
+            self.config.max_retry_after,
+        );
+        if Duration::from_secs(wait_secs) > delay {
+            warn!(
+                retry_after_secs = wait_secs,
+                capped_ms = delay.as_millis() as u64,
+                "Retry-After exceeds max_retry_after, capping wait"
+            );
+        }
+        delay
+    }
+
+    fn retry_budget_allows(&self, retry_started: Instant, delay: Duration) -> bool {
+        self.config
+            .max_retry_duration
+            .is_none_or(|budget| retry_started.elapsed() + delay <= budget)
+    }
+
     async fn request_bytes(
         &self,
         method: reqwest::Method,
@@ -487,12 +761,8 @@ impl FreeAgentClient {
         mutating: bool,
         policy: RequestPolicy,
     ) -> Result<RawBytesResponse> {
-        if mutating && !self.config.allow_writes {
-            return Err(ChoSdkError::WriteNotAllowed {
-                message:
-                    "Set [safety] allow_writes = true in config.toml to enable mutating commands"
-                        .to_string(),
-            });
+        if mutating {
+            self.ensure_writes_allowed()?;
         }
 
         let max_retries = policy
@@ -500,6 +770,7 @@ impl FreeAgentClient {
             .unwrap_or(self.config.max_retries);
         let url = build_url(&self.config.base_url, path)?;
         let mut did_refresh = false;
+        let retry_started = Instant::now();
 
         let mut attempt: u32 = 0;
 
@@ -507,21 +778,12 @@ impl FreeAgentClient {
             let started = Instant::now();
             let access_token = self.auth.get_access_token().await?;
 
-            if let Some(observer) = &self.observer {
-                observer.on_request(&HttpRequestEvent {
-                    method: method.as_str().to_string(),
-                    url: url.clone(),
-                    query: query.to_vec(),
-                    has_body: body.is_some(),
-                    mutating,
-                })?;
-            }
-
             let mut request = self
                 .http_client
                 .request(method.clone(), &url)
                 .header(reqwest::header::ACCEPT, "*/*")
                 .header(reqwest::header::USER_AGENT, &self.config.user_agent)
+                .headers(self.default_headers.clone())
                 .bearer_auth(access_token)
                 .query(query);
 
@@ -535,7 +797,21 @@ impl FreeAgentClient {
                     .json(payload);
             }
 
-            let result = request.send().await;
+            let request = request.build().map_err(ChoSdkError::Network)?;
+
+            if let Some(observer) = &self.observer {
+                observer.on_request(&HttpRequestEvent {
+                    method: method.as_str().to_string(),
+                    url: url.clone(),
+                    query: query.to_vec(),
+                    has_body: body.is_some(),
+                    mutating,
+                    full_url: request.url().to_string(),
+                    headers: redacted_headers(request.headers()),
+                })?;
+            }
+
+            let result = self.http_client.execute(request).await;
             let elapsed_ms = started.elapsed().as_millis() as u64;
 
             let response = match result {
@@ -552,8 +828,11 @@ impl FreeAgentClient {
                         })?;
                     }
 
-                    if attempt < max_retries && (err.is_connect() || err.is_timeout()) {
-                        let delay = backoff_delay(attempt);
+                    let delay = backoff_delay(attempt);
+                    if attempt < max_retries
+                        && (err.is_connect() || err.is_timeout())
+                        && self.retry_budget_allows(retry_started, delay)
+                    {
                         warn!(
                             attempt = attempt + 1,
                             max_attempts = max_retries + 1,
@@ -571,6 +850,7 @@ impl FreeAgentClient {
 
             let status = response.status();
             let headers = response.headers().clone();
+            self.record_rate_limit(&headers, status);
             let retry_after = headers
                 .get("Retry-After")
                 .and_then(|v| v.to_str().ok())
@@ -600,14 +880,20 @@ impl FreeAgentClient {
 
             if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
                 let wait = retry_after.unwrap_or(60);
-                if attempt < max_retries {
+                let delay = self.retry_after_delay(wait);
+                if attempt < max_retries && self.retry_budget_allows(retry_started, delay) {
                     attempt += 1;
-                    tokio::time::sleep(std::time::Duration::from_secs(wait)).await;
+                    tokio::time::sleep(delay).await;
                     continue;
                 }
                 return Err(ChoSdkError::RateLimited { retry_after: wait });
             }
 
+            if status == reqwest::StatusCode::FORBIDDEN {
+                let text = response.text().await.map_err(ChoSdkError::Network)?;
+                return Err(ChoSdkError::forbidden(&text));
+            }
+
             if status == reqwest::StatusCode::NOT_FOUND {
                 return Err(ChoSdkError::NotFound {
                     resource: path.to_string(),
@@ -686,6 +972,8 @@ impl FreeAgentClientBuilder {
                     .to_string(),
             });
         }
+        config.validate_base_url()?;
+        let default_headers = default_header_map(&config.default_headers)?;
 
         let auth = self.auth.ok_or_else(|| ChoSdkError::Config {
             message: "Auth manager is required".to_string(),
@@ -701,10 +989,80 @@ impl FreeAgentClientBuilder {
             auth: Arc::new(auth),
             http_client,
             observer: self.observer,
+            rate_limit: Arc::new(Mutex::new(None)),
+            default_headers,
         })
     }
 }
 
+fn default_header_map(headers: &[(String, String)]) -> Result<reqwest::header::HeaderMap> {
+    let mut map = reqwest::header::HeaderMap::new();
+    for (name, value) in headers {
+        let invalid = |reason: String| ChoSdkError::Config {
+            message: format!("Invalid default header '{name}': {reason}"),
+        };
+        let name = reqwest::header::HeaderName::from_bytes(name.trim().as_bytes())
+            .map_err(|e| invalid(e.to_string()))?;
+        if name == reqwest::header::AUTHORIZATION {
+            return Err(invalid(
+                "authorization is managed by the auth manager".to_string(),
+            ));
+        }
+        let value = reqwest::header::HeaderValue::from_str(value.trim())
+            .map_err(|e| invalid(e.to_string()))?;
+        map.insert(name, value);
+    }
+    Ok(map)
+}
+
+fn rate_limit_from_headers(
+    headers: &reqwest::header::HeaderMap,
+    status: reqwest::StatusCode,
+) -> Option<RateLimitSnapshot> {
+    let read = |names: &[&str]| {
+        names.iter().find_map(|name| {
+            headers
+                .get(*name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        })
+    };
+
+    let limited = status == reqwest::StatusCode::TOO_MANY_REQUESTS;
+    let snapshot = RateLimitSnapshot {
+        limit: read(&["x-ratelimit-limit", "ratelimit-limit"]),
+        remaining: read(&["x-ratelimit-remaining", "ratelimit-remaining"]).or(limited.then_some(0)),
+        reset_secs: read(&["x-ratelimit-reset", "ratelimit-reset"]),
+        retry_after: read(&["retry-after"]).filter(|_| limited),
+    };
+
+    (snapshot != RateLimitSnapshot::default()).then_some(snapshot)
+}
+
+fn redacted_headers(headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if name == reqwest::header::AUTHORIZATION {
+                "Bearer [REDACTED]".to_string()
+            } else if is_sensitive_header(name.as_str()) {
+                "[REDACTED]".to_string()
+            } else {
+                value.to_str().unwrap_or("[binary]").to_string()
+            };
+            (name.as_str().to_string(), value)
+        })
+        .collect()
+}
+
+/// Header names whose values may carry credentials (names are lower-case).
+fn is_sensitive_header(name: &str) -> bool {
+    matches!(name, "proxy-authorization" | "cookie" | "set-cookie")
+        || name.ends_with("-token")
+        || name.ends_with("-key")
+        || name.contains("secret")
+}
+
 fn extract_collection(body: &Value, collection_key: &str) -> Result<Vec<Value>> {
     let array = body
         .get(collection_key)
@@ -716,7 +1074,8 @@ fn extract_collection(body: &Value, collection_key: &str) -> Result<Vec<Value>>
     Ok(array.clone())
 }
 
-fn response_has_next_link(headers: &reqwest::header::HeaderMap) -> bool {
+/// True when a list response's `Link` header advertises a `rel="next"` page.
+pub fn has_more_pages(headers: &reqwest::header::HeaderMap) -> bool {
     let Some(link) = headers.get("Link") else {
         return false;
     };
@@ -810,7 +1169,12 @@ fn normalize_base_path(path: &str) -> String {
     normalized
 }
 
-fn backoff_delay(attempt: u32) -> std::time::Duration {
+/// Clamps a `Retry-After` value into `min..=max` (`max` wins if they cross).
+fn clamp_retry_after(wait_secs: u64, min: Duration, max: Duration) -> Duration {
+    Duration::from_secs(wait_secs).max(min).min(max)
+}
+
+pub(crate) fn backoff_delay(attempt: u32) -> std::time::Duration {
     let base_secs = 1_u64 << attempt.min(4);
     std::time::Duration::from_secs(base_secs)
 }
@@ -819,6 +1183,72 @@ fn backoff_delay(attempt: u32) -> std::time::Duration {
 mod tests {
     use super::*;
 
+    #[test]
+    fn rate_limit_from_headers_reads_quota_headers_and_429_hints() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(
+            rate_limit_from_headers(&headers, reqwest::StatusCode::OK),
+            None
+        );
+
+        headers.insert("X-RateLimit-Limit", "120".parse().expect("header"));
+        headers.insert("X-RateLimit-Remaining", "87".parse().expect("header"));
+        let snapshot = rate_limit_from_headers(&headers, reqwest::StatusCode::OK).expect("quota");
+        assert_eq!(snapshot.limit, Some(120));
+        assert_eq!(snapshot.remaining, Some(87));
+        assert_eq!(snapshot.retry_after, None);
+
+        let mut limited = reqwest::header::HeaderMap::new();
+        limited.insert("Retry-After", "30".parse().expect("header"));
+        let snapshot = rate_limit_from_headers(&limited, reqwest::StatusCode::TOO_MANY_REQUESTS)
+            .expect("429 snapshot");
+        assert_eq!(snapshot.remaining, Some(0));
+        assert_eq!(snapshot.retry_after, Some(30));
+    }
+
+    #[test]
+    fn redacted_headers_hides_authorization_value() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            "Bearer secret-token".parse().expect("header"),
+        );
+        headers.insert(
+            reqwest::header::ACCEPT,
+            "application/json".parse().expect("header"),
+        );
+
+        let redacted = redacted_headers(&headers);
+        assert!(redacted.contains(&("authorization".to_string(), "Bearer [REDACTED]".to_string())));
+        assert!(redacted.contains(&("accept".to_string(), "application/json".to_string())));
+        assert!(!format!("{redacted:?}").contains("secret-token"));
+    }
+
+    #[test]
+    fn redacted_headers_hides_credential_like_default_headers() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        for name in [
+            "cookie",
+            "x-api-key",
+            "x-csrf-token",
+            "x-client-secret",
+            "proxy-authorization",
+        ] {
+            headers.insert(
+                reqwest::header::HeaderName::from_static(name),
+                "hunter2".parse().expect("header"),
+            );
+        }
+        headers.insert(
+            reqwest::header::USER_AGENT,
+            "cho/test".parse().expect("header"),
+        );
+
+        let redacted = redacted_headers(&headers);
+        assert!(!format!("{redacted:?}").contains("hunter2"));
+        assert!(redacted.contains(&("user-agent".to_string(), "cho/test".to_string())));
+    }
+
     #[test]
     fn extract_collection_returns_array_items() {
         let body = serde_json::json!({
@@ -843,7 +1273,7 @@ mod tests {
     }
 
     #[test]
-    fn response_has_next_link_detects_next_relation() {
+    fn has_more_pages_detects_next_relation() {
         let mut headers = reqwest::header::HeaderMap::new();
         headers.insert(
             "Link",
@@ -852,11 +1282,11 @@ mod tests {
             ),
         );
 
-        assert!(response_has_next_link(&headers));
+        assert!(has_more_pages(&headers));
     }
 
     #[test]
-    fn response_has_next_link_returns_false_without_next_relation() {
+    fn has_more_pages_returns_false_without_next_relation() {
         let mut headers = reqwest::header::HeaderMap::new();
         headers.insert(
             "Link",
@@ -865,7 +1295,7 @@ mod tests {
             ),
         );
 
-        assert!(!response_has_next_link(&headers));
+        assert!(!has_more_pages(&headers));
     }
 
     #[test]
@@ -913,6 +1343,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn clamp_retry_after_applies_floor_and_cap() {
+        let min = Duration::from_secs(1);
+        let max = Duration::from_secs(120);
+        assert_eq!(clamp_retry_after(0, min, max), min);
+        assert_eq!(clamp_retry_after(30, min, max), Duration::from_secs(30));
+        assert_eq!(clamp_retry_after(3600, min, max), max);
+        assert_eq!(clamp_retry_after(5, max, min), min);
+    }
+
     #[test]
     fn backoff_delay_caps_growth_at_sixteen_seconds() {
         assert_eq!(backoff_delay(0), std::time::Duration::from_secs(1));
@@ -1,7 +1,15 @@
 //! SDK runtime configuration.
 
+use std::path::PathBuf;
 use std::time::Duration;
 
+use url::Url;
+
+use crate::error::{ChoSdkError, Result};
+
+const PRODUCTION_API_ROOT: &str = "https://api.freeagent.com/v2/";
+const SANDBOX_API_ROOT: &str = "https://api.sandbox.freeagent.com/v2/";
+
 /// Runtime SDK configuration.
 #[derive(Debug, Clone)]
 pub struct SdkConfig {
@@ -15,27 +23,60 @@ pub struct SdkConfig {
     pub timeout: Duration,
     /// Maximum retries for transient failures.
     pub max_retries: u32,
+    /// Wall-clock cap on time spent retrying one request (`None` means unbounded).
+    pub max_retry_duration: Option<Duration>,
+    /// Longest `Retry-After` wait honoured before retrying a 429.
+    pub max_retry_after: Duration,
+    /// Shortest wait before retrying a 429, so `Retry-After: 0` cannot spin.
+    pub min_retry_after: Duration,
     /// Whether mutating operations are allowed.
+    ///
+    /// This is the only write gate: the CLI copies `[safety] allow_writes`
+    /// into it when building the client and checks it through
+    /// [`crate::client::FreeAgentClient::ensure_writes_allowed`].
     pub allow_writes: bool,
     /// User-Agent header value.
     pub user_agent: String,
+    /// Extra headers sent with every API request (tracing ids, `Prefer`, ...).
+    pub default_headers: Vec<(String, String)>,
+    /// Token store override (`None` uses [`crate::home::token_path`]).
+    pub token_file: Option<PathBuf>,
 }
 
 impl Default for SdkConfig {
     fn default() -> Self {
+        Self::for_api_root(PRODUCTION_API_ROOT)
+    }
+}
+
+impl SdkConfig {
+    /// Production FreeAgent endpoints (same as [`Default`]).
+    pub fn production() -> Self {
+        Self::default()
+    }
+
+    /// FreeAgent sandbox endpoints for testing against sandbox companies.
+    pub fn sandbox() -> Self {
+        Self::for_api_root(SANDBOX_API_ROOT)
+    }
+
+    fn for_api_root(root: &str) -> Self {
         Self {
-            base_url: "https://api.freeagent.com/v2/".to_string(),
-            authorize_url: "https://api.freeagent.com/v2/approve_app".to_string(),
-            token_url: "https://api.freeagent.com/v2/token_endpoint".to_string(),
+            base_url: root.to_string(),
+            authorize_url: format!("{root}approve_app"),
+            token_url: format!("{root}token_endpoint"),
             timeout: Duration::from_secs(30),
             max_retries: 3,
+            max_retry_duration: None,
+            max_retry_after: Duration::from_secs(120),
+            min_retry_after: Duration::from_secs(1),
             allow_writes: false,
             user_agent: format!("cho/{}", env!("CARGO_PKG_VERSION")),
+            default_headers: Vec::new(),
+            token_file: None,
         }
     }
-}
 
-impl SdkConfig {
     /// Sets API base URL.
     pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
         self.base_url = url.into();
@@ -66,6 +107,24 @@ impl SdkConfig {
         self
     }
 
+    /// Sets the wall-clock retry budget per request.
+    pub fn with_max_retry_duration(mut self, duration: Duration) -> Self {
+        self.max_retry_duration = Some(duration);
+        self
+    }
+
+    /// Sets the longest `Retry-After` wait honoured on a 429.
+    pub fn with_max_retry_after(mut self, duration: Duration) -> Self {
+        self.max_retry_after = duration;
+        self
+    }
+
+    /// Sets the shortest wait before retrying a 429.
+    pub fn with_min_retry_after(mut self, duration: Duration) -> Self {
+        self.min_retry_after = duration;
+        self
+    }
+
     /// Enables/disables mutating calls.
     pub fn with_allow_writes(mut self, allow: bool) -> Self {
         self.allow_writes = allow;
@@ -78,6 +137,25 @@ impl SdkConfig {
         self
     }
 
+    /// Adds a header sent with every API request.
+    ///
+    /// Later values for the same name replace earlier ones, including the
+    /// built-in `Accept`/`Content-Type`/`User-Agent` headers.
+    pub fn with_default_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Stores OAuth tokens in `path` instead of the default token file.
+    pub fn with_token_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.token_file = Some(path.into());
+        self
+    }
+
     /// Returns true when base/token/auth URLs are all http or https.
     pub fn is_valid_url_scheme(&self) -> bool {
         [
@@ -88,4 +166,67 @@ impl SdkConfig {
         .iter()
         .all(|u| u.starts_with("https://") || u.starts_with("http://"))
     }
+
+    /// Checks that `base_url` parses and targets an https host.
+    ///
+    /// Plain http is accepted only for loopback hosts so local mocks keep working.
+    pub fn validate_base_url(&self) -> Result<()> {
+        let reject = |reason: &str| ChoSdkError::Config {
+            message: format!("Unusable SDK base_url '{}': {reason}", self.base_url),
+        };
+
+        let url = Url::parse(&self.base_url).map_err(|e| reject(&e.to_string()))?;
+        let host = url
+            .host_str()
+            .ok_or_else(|| reject("missing host"))?
+            .trim_matches(['[', ']']);
+
+        match url.scheme() {
+            "https" => {}
+            "http" if matches!(host, "localhost" | "127.0.0.1" | "::1") => {}
+            _ => return Err(reject("only https is allowed outside loopback hosts")),
+        }
+
+        if !url.username().is_empty() || url.password().is_some() {
+            return Err(reject("credentials must not be embedded in the URL"));
+        }
+        if url.query().is_some() || url.fragment().is_some() {
+            return Err(reject("query strings and fragments are not allowed"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_share_one_api_root() {
+        let sandbox = SdkConfig::sandbox();
+        assert_eq!(sandbox.base_url, "https://api.sandbox.freeagent.com/v2/");
+        assert_eq!(
+            sandbox.token_url,
+            "https://api.sandbox.freeagent.com/v2/token_endpoint"
+        );
+        assert_eq!(
+            SdkConfig::production().authorize_url,
+            SdkConfig::default().authorize_url
+        );
+        assert!(sandbox.validate_base_url().is_ok());
+    }
+
+    #[test]
+    fn validate_base_url_requires_https_except_on_loopback() {
+        let with = |url: &str| SdkConfig::default().with_base_url(url).validate_base_url();
+
+        assert!(with("https://api.freeagent.com/v2").is_ok());
+        assert!(with("http://127.0.0.1:4010/v2/").is_ok());
+        assert!(with("http://localhost/v2/").is_ok());
+        assert!(with("http://api.freeagent.com/v2/").is_err());
+        assert!(with("api.freeagent.com/v2/").is_err());
+        assert!(with("https://user:pw@api.freeagent.com/v2/").is_err());
+        assert!(with("https://api.freeagent.com/v2/?debug=1").is_err());
+    }
 }
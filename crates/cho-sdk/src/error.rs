@@ -2,6 +2,8 @@
 
 use std::fmt;
 
+use serde_json::Value;
+
 /// Convenience result alias.
 pub type Result<T> = std::result::Result<T, ChoSdkError>;
 
@@ -38,6 +40,13 @@ pub enum ChoSdkError {
         message: String,
     },
 
+    /// API refused the request for the current user or app (HTTP 403).
+    #[error("forbidden: {message}")]
+    Forbidden {
+        /// Human-readable detail.
+        message: String,
+    },
+
     /// Requested resource does not exist.
     #[error("{resource} not found: {id}")]
     NotFound {
@@ -65,6 +74,15 @@ pub enum ChoSdkError {
         message: String,
     },
 
+    /// Payload failed local validation before any request was sent.
+    #[error("invalid {resource}: {}", .errors.join("; "))]
+    Validation {
+        /// Payload kind that was rejected.
+        resource: String,
+        /// Every violation found, in field order.
+        errors: Vec<String>,
+    },
+
     /// Write operations are disabled.
     #[error("write operations not allowed: {message}")]
     WriteNotAllowed {
@@ -81,4 +99,108 @@ impl ChoSdkError {
             message: body.to_string(),
         }
     }
+
+    /// Converts a 403 response body into [`Self::Forbidden`].
+    ///
+    /// Uses FreeAgent's error messages when present; a non-JSON body is used
+    /// verbatim.
+    pub fn forbidden(body: &str) -> Self {
+        let trimmed = body.trim();
+        let messages = api_error_messages(trimmed);
+        let message = if !messages.is_empty() {
+            messages.join("; ")
+        } else if !trimmed.is_empty() && serde_json::from_str::<Value>(trimmed).is_err() {
+            trimmed.to_string()
+        } else {
+            "access denied".to_string()
+        };
+
+        Self::Forbidden { message }
+    }
+}
+
+/// Extracts error messages from FreeAgent's `{"errors": ...}` response shapes.
+///
+/// Handles `{"errors":{"error":{...}}}`, an array under `error`, and a bare
+/// `errors` array, with object entries carrying `message` or plain strings.
+/// Duplicates are dropped; anything else yields an empty list.
+pub fn api_error_messages(body: &str) -> Vec<String> {
+    let Ok(value) = serde_json::from_str::<Value>(body) else {
+        return Vec::new();
+    };
+
+    let entries = match value.get("errors") {
+        Some(Value::Object(errors)) => match errors.get("error") {
+            Some(Value::Array(items)) => items.iter().collect(),
+            Some(item) => vec![item],
+            None => Vec::new(),
+        },
+        Some(Value::Array(items)) => items.iter().collect(),
+        _ => Vec::new(),
+    };
+
+    let mut messages: Vec<String> = Vec::new();
+    for entry in entries {
+        let text = match entry {
+            Value::String(text) => Some(text.as_str()),
+            Value::Object(_) => entry.get("message").and_then(Value::as_str),
+            _ => None,
+        };
+        if let Some(text) = text.map(str::trim).filter(|text| !text.is_empty())
+            && !messages.iter().any(|existing| existing == text)
+        {
+            messages.push(text.to_string());
+        }
+    }
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forbidden_reads_nested_freeagent_error_message() {
+        let err = ChoSdkError::forbidden(
+            r#"{"errors":{"error":{"message":"You do not have permission to access bills"}}}"#,
+        );
+
+        assert!(matches!(
+            err,
+            ChoSdkError::Forbidden { ref message }
+                if message == "You do not have permission to access bills"
+        ));
+    }
+
+    #[test]
+    fn forbidden_falls_back_to_plain_body_or_default_message() {
+        assert!(matches!(
+            ChoSdkError::forbidden("Forbidden"),
+            ChoSdkError::Forbidden { message } if message == "Forbidden"
+        ));
+        assert!(matches!(
+            ChoSdkError::forbidden(""),
+            ChoSdkError::Forbidden { message } if message == "access denied"
+        ));
+        assert!(matches!(
+            ChoSdkError::forbidden(r#"{"message":"unexpected shape"}"#),
+            ChoSdkError::Forbidden { message } if message == "access denied"
+        ));
+    }
+
+    #[test]
+    fn api_error_messages_reads_freeagent_error_shapes() {
+        assert_eq!(
+            api_error_messages(r#"{"errors":{"error":{"message":"Dated on is invalid"}}}"#),
+            vec!["Dated on is invalid"]
+        );
+        assert_eq!(
+            api_error_messages(
+                r#"{"errors":[{"message":"Contact can't be blank"},{"message":"Total must be positive"},{"message":"Contact can't be blank"}]}"#
+            ),
+            vec!["Contact can't be blank", "Total must be positive"]
+        );
+        assert!(api_error_messages("<html>Bad Gateway</html>").is_empty());
+        assert!(api_error_messages(r#"{"invoice":{"url":"x"}}"#).is_empty());
+    }
 }
@@ -51,10 +51,36 @@ pub fn history_log_path() -> Result<PathBuf> {
 }
 
 /// Path to token store file.
+///
+/// `CHO_TOKEN_FILE` overrides the default `tokens.json` under the `cho` home so
+/// CI jobs and multi-account setups can isolate tokens per account. The file is
+/// plaintext JSON either way; keep it on storage only the current user can read.
 pub fn token_path() -> Result<PathBuf> {
+    if let Some(path) = std::env::var_os("CHO_TOKEN_FILE").filter(|value| !value.is_empty()) {
+        let path = PathBuf::from(path);
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            && !parent.exists()
+        {
+            std::fs::create_dir_all(parent).map_err(|e| ChoSdkError::Config {
+                message: format!(
+                    "Failed to create token file directory {}: {e}",
+                    parent.display()
+                ),
+            })?;
+        }
+        return Ok(path);
+    }
+
     Ok(ensure_cho_home()?.join("tokens.json"))
 }
 
+/// Default token store for a named config profile (`tokens-<profile>.json`).
+pub fn profile_token_path(profile: &str) -> Result<PathBuf> {
+    Ok(ensure_cho_home()?.join(format!("tokens-{profile}.json")))
+}
+
 /// Path to TUI route cache file.
 pub fn tui_cache_path() -> Result<PathBuf> {
     Ok(ensure_cho_home()?.join("tui-cache.json"))
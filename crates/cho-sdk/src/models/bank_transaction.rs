@@ -0,0 +1,132 @@
+//! Bank transaction and statement line models.
+
+use serde::{Deserialize, Serialize};
+
+use super::dates::ApiDate;
+
+/// Imported bank transaction.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BankTransaction {
+    /// Transaction URL.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Owning bank account URL.
+    #[serde(default)]
+    pub bank_account: Option<String>,
+    /// Transaction date.
+    #[serde(default)]
+    pub dated_on: Option<ApiDate>,
+    /// Signed amount (money in is positive).
+    #[serde(default)]
+    pub amount: Option<String>,
+    /// Statement description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Portion of the amount not yet explained.
+    #[serde(default)]
+    pub unexplained_amount: Option<String>,
+    /// True when entered manually rather than imported.
+    #[serde(default)]
+    pub is_manual: Option<bool>,
+}
+
+impl BankTransaction {
+    /// True when the full amount has been explained.
+    ///
+    /// FreeAgent has no reconciled flag; a transaction counts as reconciled
+    /// once its `unexplained_amount` reaches zero.
+    pub fn is_reconciled(&self) -> bool {
+        self.unexplained_amount
+            .as_deref()
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .is_some_and(|value| value.abs() < 0.005)
+    }
+}
+
+/// One line of a bank statement upload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatementLine {
+    /// Transaction date.
+    pub dated_on: ApiDate,
+    /// Signed amount as a decimal string (money in is positive).
+    pub amount: String,
+    /// Statement description.
+    pub description: String,
+    /// Bank-assigned unique id, used by FreeAgent to skip duplicates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fitid: Option<String>,
+}
+
+impl StatementLine {
+    /// Checks fields FreeAgent requires on an uploaded statement line.
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        match self.amount.trim().parse::<f64>() {
+            Ok(value) if value.is_finite() && value != 0.0 => {}
+            _ => {
+                return Err(format!(
+                    "amount must be a non-zero decimal, got {}",
+                    self.amount
+                ));
+            }
+        }
+        if self.description.trim().is_empty() {
+            return Err("description is required".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_reconciled_follows_unexplained_amount() {
+        let with = |unexplained: Option<&str>| BankTransaction {
+            unexplained_amount: unexplained.map(str::to_string),
+            ..BankTransaction::default()
+        };
+
+        assert!(with(Some("0.0")).is_reconciled());
+        assert!(!with(Some("-12.50")).is_reconciled());
+        assert!(!with(None).is_reconciled());
+    }
+
+    #[test]
+    fn dated_on_parses_as_a_date() {
+        let transaction: BankTransaction =
+            serde_json::from_str(r#"{"dated_on":"2026-02-01","amount":"-4.20"}"#)
+                .expect("transaction should parse");
+        assert_eq!(transaction.dated_on, ApiDate::from_ymd(2026, 2, 1));
+
+        assert!(serde_json::from_str::<BankTransaction>(r#"{"dated_on":"01/02/2026"}"#).is_err());
+    }
+
+    #[test]
+    fn statement_line_validation_rejects_bad_amounts_and_descriptions() {
+        let line = StatementLine {
+            dated_on: ApiDate::from_ymd(2026, 2, 1).expect("valid date"),
+            amount: "-42.10".to_string(),
+            description: "Card payment".to_string(),
+            fitid: None,
+        };
+        assert_eq!(line.validate(), Ok(()));
+
+        for invalid in [
+            StatementLine {
+                amount: "abc".to_string(),
+                ..line.clone()
+            },
+            StatementLine {
+                amount: "0".to_string(),
+                ..line.clone()
+            },
+            StatementLine {
+                description: " ".to_string(),
+                ..line.clone()
+            },
+        ] {
+            assert!(invalid.validate().is_err(), "{invalid:?} accepted");
+        }
+    }
+}
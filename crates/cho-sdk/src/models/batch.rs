@@ -0,0 +1,35 @@
+//! Per-row outcomes for client-side batch writes.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Outcome of a batch write that continues past individual row failures.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BatchResult {
+    /// Created resources, in input order.
+    pub succeeded: Vec<Value>,
+    /// Rows that could not be created.
+    pub failed: Vec<BatchFailure>,
+    /// Indexes of rows never sent because the batch stopped early.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub not_attempted: Vec<usize>,
+    /// Indexes of rows accepted by a bulk upload that returns no resources.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub submitted: Vec<usize>,
+}
+
+/// One failed row in a batch write.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchFailure {
+    /// Zero-based position of the row in the input slice.
+    pub index: usize,
+    /// Error reported for the row.
+    pub error: String,
+}
+
+impl BatchResult {
+    /// True when every row was created.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty() && self.not_attempted.is_empty()
+    }
+}
@@ -0,0 +1,231 @@
+//! Chart-of-accounts category helpers.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::{ChoSdkError, Result};
+
+/// Category class matching the groups FreeAgent returns from `categories`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CategoryClass {
+    /// Sales and other income.
+    Income,
+    /// Direct costs of sales.
+    CostOfSales,
+    /// Administrative expenses.
+    AdminExpenses,
+    /// Balance sheet and other general categories.
+    General,
+}
+
+impl CategoryClass {
+    /// Response key FreeAgent uses for this class.
+    pub fn group_key(self) -> &'static str {
+        match self {
+            Self::Income => "income_categories",
+            Self::CostOfSales => "cost_of_sales_categories",
+            Self::AdminExpenses => "admin_expenses_categories",
+            Self::General => "general_categories",
+        }
+    }
+
+    /// Resolves a class from a FreeAgent response group key.
+    pub fn from_group_key(key: &str) -> Option<Self> {
+        [
+            Self::Income,
+            Self::CostOfSales,
+            Self::AdminExpenses,
+            Self::General,
+        ]
+        .into_iter()
+        .find(|class| class.group_key() == key)
+    }
+}
+
+/// Category entry from the FreeAgent chart of accounts.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Category {
+    /// Category URL.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Display name.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Nominal code (`001`, `285`, `750-1`, ...).
+    #[serde(default)]
+    pub nominal_code: Option<String>,
+    /// Group key the category was listed under.
+    #[serde(default)]
+    pub category_group: Option<String>,
+    /// Whether spending in this category is tax deductible.
+    #[serde(default)]
+    pub allowable_for_tax: Option<bool>,
+    /// Tax reporting bucket name.
+    #[serde(default)]
+    pub tax_reporting_name: Option<String>,
+}
+
+impl Category {
+    /// Class derived from the response group.
+    pub fn class(&self) -> Option<CategoryClass> {
+        self.category_group
+            .as_deref()
+            .and_then(CategoryClass::from_group_key)
+    }
+
+    /// True for income categories.
+    pub fn is_income(&self) -> bool {
+        self.class() == Some(CategoryClass::Income)
+    }
+
+    /// True for cost-of-sales and admin expense categories.
+    pub fn is_expense(&self) -> bool {
+        matches!(
+            self.class(),
+            Some(CategoryClass::CostOfSales | CategoryClass::AdminExpenses)
+        )
+    }
+
+    /// True for bank account categories (nominal codes `750-n`).
+    pub fn is_bank(&self) -> bool {
+        self.nominal_code
+            .as_deref()
+            .is_some_and(|code| code.trim().starts_with("750-"))
+    }
+}
+
+/// Flattens the grouped `categories` response into raw entries.
+///
+/// Each group is an array of categories, or a single object when it holds
+/// one. Object entries without a `category_group` are tagged with the key of
+/// the group they were listed under; all other fields are kept as returned.
+pub fn flatten_category_groups(value: &Value) -> Vec<Value> {
+    let Some(object) = value.as_object() else {
+        return Vec::new();
+    };
+
+    let mut out = Vec::new();
+    for (group_name, group_value) in object {
+        let items = match group_value {
+            Value::Array(items) => items.iter().collect::<Vec<_>>(),
+            Value::Object(_) => vec![group_value],
+            _ => continue,
+        };
+
+        for item in items {
+            let mut item = item.clone();
+            if let Value::Object(map) = &mut item
+                && !map.contains_key("category_group")
+            {
+                map.insert(
+                    "category_group".to_string(),
+                    Value::String(group_name.clone()),
+                );
+            }
+            out.push(item);
+        }
+    }
+
+    out
+}
+
+/// Parses the grouped `categories` response, tagging each entry with its group.
+///
+/// Fails on the first entry that is not a category rather than skipping it.
+pub fn categories_from_grouped(value: &Value) -> Result<Vec<Category>> {
+    flatten_category_groups(value)
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            serde_json::from_value(item).map_err(|e| ChoSdkError::Parse {
+                message: format!("Failed to parse category {index}: {e}"),
+            })
+        })
+        .collect()
+}
+
+/// Returns categories belonging to one class.
+pub fn filter_by_class(categories: &[Category], class: CategoryClass) -> Vec<&Category> {
+    categories
+        .iter()
+        .filter(|category| category.class() == Some(class))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grouped() -> Value {
+        serde_json::json!({
+            "income_categories": [
+                { "nominal_code": "001", "description": "Sales" }
+            ],
+            "admin_expenses_categories": [
+                { "nominal_code": "285", "description": "Accommodation and Meals" }
+            ],
+            "cost_of_sales_categories": {
+                "nominal_code": "101", "description": "Cost of sales"
+            },
+            "general_categories": [
+                { "nominal_code": "750-1", "description": "Business Current Account" }
+            ]
+        })
+    }
+
+    #[test]
+    fn categories_from_grouped_tags_groups_and_filters_by_class() {
+        let categories = categories_from_grouped(&grouped()).expect("categories parse");
+        assert_eq!(categories.len(), 4);
+
+        let income = filter_by_class(&categories, CategoryClass::Income);
+        assert_eq!(income.len(), 1);
+        assert_eq!(income[0].nominal_code.as_deref(), Some("001"));
+
+        let cost_of_sales = filter_by_class(&categories, CategoryClass::CostOfSales);
+        assert_eq!(cost_of_sales.len(), 1);
+    }
+
+    #[test]
+    fn predicates_follow_class_and_nominal_code() {
+        let categories = categories_from_grouped(&grouped()).expect("categories parse");
+        let by_code = |code: &str| {
+            categories
+                .iter()
+                .find(|category| category.nominal_code.as_deref() == Some(code))
+                .expect("category present")
+        };
+
+        assert!(by_code("001").is_income());
+        assert!(by_code("285").is_expense());
+        assert!(by_code("101").is_expense());
+        assert!(by_code("750-1").is_bank());
+        assert!(!by_code("750-1").is_expense());
+    }
+
+    #[test]
+    fn flatten_category_groups_tags_array_and_single_object_groups() {
+        let items = flatten_category_groups(&grouped());
+        assert_eq!(items.len(), 4);
+        assert!(items.iter().any(|item| item["nominal_code"] == "101"
+            && item["category_group"] == "cost_of_sales_categories"));
+
+        let tagged = serde_json::json!({
+            "general_categories": [{ "nominal_code": "051", "category_group": "custom" }]
+        });
+        assert_eq!(
+            flatten_category_groups(&tagged)[0]["category_group"],
+            "custom"
+        );
+    }
+
+    #[test]
+    fn categories_from_grouped_reports_unparseable_entries() {
+        let err = categories_from_grouped(&serde_json::json!({
+            "income_categories": [{ "nominal_code": "001" }, { "nominal_code": 1 }]
+        }))
+        .expect_err("numeric nominal code");
+        assert!(err.to_string().contains("category 1"));
+    }
+}
@@ -0,0 +1,78 @@
+//! Company settings.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::{ChoSdkError, Result};
+
+/// Company record from the `company` endpoint.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Company {
+    /// Company URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Company name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// FreeAgent subdomain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subdomain: Option<String>,
+    /// Company type (`UkLimitedCompany`, `UkSoleTrader`, ...).
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub company_type: Option<String>,
+    /// Native currency code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Sales tax registration status (`Registered` or `Not Registered`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sales_tax_registration_status: Option<String>,
+    /// Tax label shown on invoices (`VAT`, `GST`, ...).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sales_tax_name: Option<String>,
+}
+
+impl Company {
+    /// Parses a `company` response, with or without the `company` wrapper.
+    pub fn from_response(value: &Value) -> Result<Self> {
+        let body = value.get("company").unwrap_or(value);
+        serde_json::from_value(body.clone()).map_err(|e| ChoSdkError::Parse {
+            message: format!("Failed to parse company: {e}"),
+        })
+    }
+
+    /// True when the company is registered for sales tax.
+    pub fn is_sales_tax_registered(&self) -> bool {
+        self.sales_tax_registration_status
+            .as_deref()
+            .is_some_and(|status| status.trim().eq_ignore_ascii_case("registered"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn company_response_parses_wrapped_body() {
+        let company = Company::from_response(&serde_json::json!({
+            "company": {
+                "name": "Acme Ltd",
+                "type": "UkLimitedCompany",
+                "currency": "GBP",
+                "sales_tax_registration_status": "Registered"
+            }
+        }))
+        .expect("company parses");
+
+        assert_eq!(company.name.as_deref(), Some("Acme Ltd"));
+        assert_eq!(company.company_type.as_deref(), Some("UkLimitedCompany"));
+        assert!(company.is_sales_tax_registered());
+    }
+
+    #[test]
+    fn company_response_reports_parse_errors() {
+        let err = Company::from_response(&serde_json::json!({ "company": { "name": 42 } }))
+            .expect_err("numeric name should fail");
+        assert!(matches!(err, ChoSdkError::Parse { .. }));
+    }
+}
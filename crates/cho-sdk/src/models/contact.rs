@@ -0,0 +1,308 @@
+//! Contact payloads and builder.
+
+use serde::{Deserialize, Serialize};
+
+/// Sales tax treatment applied to a contact's invoices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChargeSalesTax {
+    /// Charge tax based on the contact's country.
+    Auto,
+    /// Always charge sales tax.
+    Always,
+    /// Never charge sales tax.
+    Never,
+}
+
+/// Contact as sent to `contacts` create/update.
+///
+/// FreeAgent uses one contact record for customers and suppliers, with a
+/// single postal address and separate phone fields.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Contact {
+    /// Contact URL (set by FreeAgent).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Company name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organisation_name: Option<String>,
+    /// Person first name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// Person last name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Email address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Landline number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    /// Mobile number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<String>,
+    /// First address line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    /// Second address line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    /// Third address line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address3: Option<String>,
+    /// Town or city.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub town: Option<String>,
+    /// County, state, or region.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// Postcode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postcode: Option<String>,
+    /// Country name as FreeAgent lists it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// Default payment terms for new invoices.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_payment_terms_in_days: Option<u32>,
+    /// Sales tax treatment for new invoices.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub charge_sales_tax: Option<ChargeSalesTax>,
+    /// Contact's own sales tax registration number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sales_tax_registration_number: Option<String>,
+}
+
+impl Contact {
+    /// Starts a builder for a new contact.
+    pub fn builder() -> ContactBuilder {
+        ContactBuilder::default()
+    }
+
+    /// Checks the name fields FreeAgent requires before a contact can be created.
+    pub fn validate_for_create(&self) -> std::result::Result<(), String> {
+        let present = |value: &Option<String>| {
+            value
+                .as_deref()
+                .is_some_and(|value| !value.trim().is_empty())
+        };
+        if present(&self.organisation_name) || present(&self.first_name) || present(&self.last_name)
+        {
+            Ok(())
+        } else {
+            Err("organisation_name or first_name/last_name is required".to_string())
+        }
+    }
+}
+
+/// Contact as FreeAgent returns it, with read-only account fields.
+///
+/// FreeAgent has no contact groups or attachment flags; the extra fields on
+/// a fetched contact are its status, balance, and project count.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContactDetail {
+    /// Editable contact fields.
+    #[serde(flatten)]
+    pub contact: Contact,
+    /// Contact status (`Active` or `Hidden`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Outstanding balance as a decimal string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_balance: Option<String>,
+    /// Number of active projects for the contact.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_projects_count: Option<u32>,
+    /// Creation timestamp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// Last update timestamp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl ContactDetail {
+    /// True unless FreeAgent reports the contact as hidden.
+    pub fn is_active(&self) -> bool {
+        !self
+            .status
+            .as_deref()
+            .is_some_and(|status| status.trim().eq_ignore_ascii_case("hidden"))
+    }
+
+    /// Outstanding balance, when present and numeric.
+    pub fn balance(&self) -> Option<f64> {
+        self.account_balance.as_deref()?.trim().parse().ok()
+    }
+}
+
+/// Postal address lines for [`ContactBuilder::address`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContactAddress {
+    /// Street lines (up to three are kept).
+    pub lines: Vec<String>,
+    /// Town or city.
+    pub town: Option<String>,
+    /// County, state, or region.
+    pub region: Option<String>,
+    /// Postcode.
+    pub postcode: Option<String>,
+    /// Country name.
+    pub country: Option<String>,
+}
+
+/// Fluent builder for [`Contact`].
+#[derive(Debug, Clone, Default)]
+pub struct ContactBuilder {
+    contact: Contact,
+}
+
+impl ContactBuilder {
+    /// Sets the company name.
+    pub fn organisation_name(mut self, name: impl Into<String>) -> Self {
+        self.contact.organisation_name = Some(name.into());
+        self
+    }
+
+    /// Sets the person's first and last name.
+    pub fn person(mut self, first_name: impl Into<String>, last_name: impl Into<String>) -> Self {
+        self.contact.first_name = Some(first_name.into());
+        self.contact.last_name = Some(last_name.into());
+        self
+    }
+
+    /// Sets the email address.
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.contact.email = Some(email.into());
+        self
+    }
+
+    /// Sets the landline number.
+    pub fn phone_number(mut self, phone: impl Into<String>) -> Self {
+        self.contact.phone_number = Some(phone.into());
+        self
+    }
+
+    /// Sets the mobile number.
+    pub fn mobile(mut self, mobile: impl Into<String>) -> Self {
+        self.contact.mobile = Some(mobile.into());
+        self
+    }
+
+    /// Sets the postal address, replacing any previous one.
+    pub fn address(mut self, address: ContactAddress) -> Self {
+        let mut lines = address.lines.into_iter();
+        self.contact.address1 = lines.next();
+        self.contact.address2 = lines.next();
+        self.contact.address3 = lines.next();
+        self.contact.town = address.town;
+        self.contact.region = address.region;
+        self.contact.postcode = address.postcode;
+        self.contact.country = address.country;
+        self
+    }
+
+    /// Sets default payment terms for new invoices.
+    pub fn payment_terms_days(mut self, days: u32) -> Self {
+        self.contact.default_payment_terms_in_days = Some(days);
+        self
+    }
+
+    /// Sets sales tax treatment for new invoices.
+    pub fn charge_sales_tax(mut self, charge: ChargeSalesTax) -> Self {
+        self.contact.charge_sales_tax = Some(charge);
+        self
+    }
+
+    /// Sets the contact's sales tax registration number.
+    pub fn sales_tax_registration_number(mut self, number: impl Into<String>) -> Self {
+        self.contact.sales_tax_registration_number = Some(number.into());
+        self
+    }
+
+    /// Returns the contact, failing when no name was set.
+    pub fn build(self) -> std::result::Result<Contact, String> {
+        self.contact.validate_for_create()?;
+        Ok(self.contact)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_flattens_address_and_serializes_set_fields_only() {
+        let contact = Contact::builder()
+            .organisation_name("Acme Ltd")
+            .email("accounts@acme.test")
+            .address(ContactAddress {
+                lines: vec!["1 High Street".to_string(), "Unit 4".to_string()],
+                town: Some("Leeds".to_string()),
+                postcode: Some("LS1 1AA".to_string()),
+                ..ContactAddress::default()
+            })
+            .payment_terms_days(14)
+            .charge_sales_tax(ChargeSalesTax::Always)
+            .build()
+            .expect("named contact");
+
+        assert_eq!(
+            serde_json::to_value(&contact).expect("contact serializes"),
+            serde_json::json!({
+                "organisation_name": "Acme Ltd",
+                "email": "accounts@acme.test",
+                "address1": "1 High Street",
+                "address2": "Unit 4",
+                "town": "Leeds",
+                "postcode": "LS1 1AA",
+                "default_payment_terms_in_days": 14,
+                "charge_sales_tax": "Always"
+            })
+        );
+    }
+
+    #[test]
+    fn contact_detail_keeps_contact_fields_and_read_only_extras() {
+        let detail: ContactDetail = serde_json::from_value(serde_json::json!({
+            "url": "https://api.freeagent.com/v2/contacts/1",
+            "organisation_name": "Acme Ltd",
+            "status": "Hidden",
+            "account_balance": "-120.50",
+            "active_projects_count": 2
+        }))
+        .expect("detail parses");
+
+        assert_eq!(
+            detail.contact.organisation_name.as_deref(),
+            Some("Acme Ltd")
+        );
+        assert!(!detail.is_active());
+        assert_eq!(detail.balance(), Some(-120.5));
+        assert_eq!(detail.active_projects_count, Some(2));
+    }
+
+    #[test]
+    fn builder_requires_a_name() {
+        let err = Contact::builder()
+            .email("nameless@example.com")
+            .build()
+            .expect_err("name missing");
+        assert!(err.contains("organisation_name"));
+
+        assert!(Contact::builder().person("Ada", "Lovelace").build().is_ok());
+    }
+
+    #[test]
+    fn validate_for_create_accepts_any_non_blank_name() {
+        let with = |organisation: Option<&str>, last: Option<&str>| Contact {
+            organisation_name: organisation.map(str::to_string),
+            last_name: last.map(str::to_string),
+            ..Contact::default()
+        };
+
+        assert!(with(Some("Acme"), None).validate_for_create().is_ok());
+        assert!(with(None, Some("Lovelace")).validate_for_create().is_ok());
+        assert!(with(Some("  "), None).validate_for_create().is_err());
+        assert!(with(None, None).validate_for_create().is_err());
+    }
+}
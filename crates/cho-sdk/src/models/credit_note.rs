@@ -0,0 +1,86 @@
+//! Credit note email payload.
+
+use serde::{Deserialize, Serialize};
+
+/// Email sent with a credit note through `credit_notes/{id}/send_email`.
+///
+/// Fields left unset fall back to the contact's email and the company's
+/// credit note email template.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreditNoteEmail {
+    /// Recipient address(es), comma separated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    /// Subject line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Message body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Also send a copy to the sending user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_to_sender: Option<bool>,
+}
+
+impl CreditNoteEmail {
+    /// Checks recipient addresses look like email addresses when set.
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        let Some(to) = self.to.as_deref() else {
+            return Ok(());
+        };
+        let mut recipients = to.split(',').map(str::trim).peekable();
+        if recipients.peek().is_none_or(|first| first.is_empty()) {
+            return Err("to must not be blank".to_string());
+        }
+        for recipient in recipients {
+            let looks_valid = recipient
+                .split_once('@')
+                .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.'));
+            if !looks_valid {
+                return Err(format!("to has an invalid address: {recipient}"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Request body FreeAgent expects.
+    pub fn to_payload(&self) -> serde_json::Value {
+        serde_json::json!({ "credit_note": { "email": self } })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_accepts_default_recipient_and_rejects_bad_addresses() {
+        assert_eq!(CreditNoteEmail::default().validate(), Ok(()));
+
+        let email = CreditNoteEmail {
+            to: Some("a@example.com, b@example.org".to_string()),
+            ..CreditNoteEmail::default()
+        };
+        assert_eq!(email.validate(), Ok(()));
+
+        for to in ["", "accounts", "a@example.com, @example.com"] {
+            let email = CreditNoteEmail {
+                to: Some(to.to_string()),
+                ..CreditNoteEmail::default()
+            };
+            assert!(email.validate().is_err(), "{to} accepted");
+        }
+    }
+
+    #[test]
+    fn payload_wraps_email_under_credit_note() {
+        let email = CreditNoteEmail {
+            subject: Some("Your refund".to_string()),
+            ..CreditNoteEmail::default()
+        };
+        assert_eq!(
+            email.to_payload(),
+            serde_json::json!({ "credit_note": { "email": { "subject": "Your refund" } } })
+        );
+    }
+}
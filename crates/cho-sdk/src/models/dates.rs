@@ -0,0 +1,112 @@
+//! Calendar date helpers for FreeAgent `YYYY-MM-DD` fields.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Days, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Calendar date as FreeAgent sends it (`dated_on`, `due_on`, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ApiDate(pub NaiveDate);
+
+impl ApiDate {
+    /// Builds a date from year, month and day; `None` when out of range.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        NaiveDate::from_ymd_opt(year, month, day).map(Self)
+    }
+
+    /// Shifts the date by whole days (negative values move backwards).
+    ///
+    /// Saturates at chrono's supported range rather than panicking.
+    pub fn add_days(self, days: i64) -> Self {
+        let shifted = if days >= 0 {
+            self.0.checked_add_days(Days::new(days.unsigned_abs()))
+        } else {
+            self.0.checked_sub_days(Days::new(days.unsigned_abs()))
+        };
+        Self(shifted.unwrap_or(if days >= 0 {
+            NaiveDate::MAX
+        } else {
+            NaiveDate::MIN
+        }))
+    }
+
+    /// Underlying chrono date.
+    pub fn naive(self) -> NaiveDate {
+        self.0
+    }
+}
+
+/// Current calendar date in the given time zone.
+pub fn today_in_tz<Tz: TimeZone>(tz: &Tz) -> ApiDate {
+    ApiDate(Utc::now().with_timezone(tz).date_naive())
+}
+
+impl From<NaiveDate> for ApiDate {
+    fn from(value: NaiveDate) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for ApiDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.format(DATE_FORMAT))
+    }
+}
+
+impl FromStr for ApiDate {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NaiveDate::parse_from_str(s.trim(), DATE_FORMAT).map(Self)
+    }
+}
+
+impl Serialize for ApiDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+
+    use super::*;
+
+    #[test]
+    fn add_days_handles_month_ends_and_negative_offsets() {
+        let issued = ApiDate::from_ymd(2026, 1, 31).expect("valid date");
+        assert_eq!(issued.add_days(30).to_string(), "2026-03-02");
+        assert_eq!(issued.add_days(-31).to_string(), "2025-12-31");
+        assert_eq!(ApiDate::from_ymd(2026, 2, 30), None);
+    }
+
+    #[test]
+    fn serde_round_trips_freeagent_date_strings() {
+        let date: ApiDate = serde_json::from_value(serde_json::json!("2026-04-05")).expect("parse");
+        assert_eq!(date, ApiDate::from_ymd(2026, 4, 5).expect("valid date"));
+        assert_eq!(
+            serde_json::to_value(date).expect("serialize"),
+            serde_json::json!("2026-04-05")
+        );
+    }
+
+    #[test]
+    fn today_in_tz_uses_the_zone_calendar_day() {
+        let ahead = FixedOffset::east_opt(14 * 3600).expect("offset");
+        let behind = FixedOffset::west_opt(12 * 3600).expect("offset");
+        let diff = today_in_tz(&ahead).naive() - today_in_tz(&behind).naive();
+        assert!((1..=2).contains(&diff.num_days()));
+    }
+}
@@ -0,0 +1,66 @@
+//! Estimate (quote) model.
+
+use serde::{Deserialize, Serialize};
+
+use super::invoice::{InvoiceItem, ItemTotals};
+
+/// Estimate with the totals FreeAgent reports and its line items.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Estimate {
+    /// Estimate URL.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Estimate reference.
+    #[serde(default)]
+    pub reference: Option<String>,
+    /// Lifecycle status (`Draft`, `Sent`, `Approved`, ...).
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Net value reported by FreeAgent.
+    #[serde(default)]
+    pub net_value: Option<String>,
+    /// Sales tax reported by FreeAgent.
+    #[serde(default)]
+    pub sales_tax_value: Option<String>,
+    /// Gross value reported by FreeAgent.
+    #[serde(default)]
+    pub total_value: Option<String>,
+    /// Line items.
+    #[serde(default)]
+    pub estimate_items: Vec<InvoiceItem>,
+}
+
+impl Estimate {
+    /// Totals computed from line items, independent of the reported values.
+    pub fn computed_totals(&self) -> ItemTotals {
+        ItemTotals::from_items(&self.estimate_items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computed_totals_sum_lines_and_skip_comment_rows() {
+        let estimate: Estimate = serde_json::from_value(serde_json::json!({
+            "status": "Draft",
+            "estimate_items": [
+                { "description": "Design", "quantity": "2", "price": "150.00", "sales_tax_rate": "20.0" },
+                { "description": "Hosting", "quantity": "1", "price": "9.99", "sales_tax_rate": "20.0" },
+                { "description": "Phase two (comment)", "item_type": "Comment" },
+                { "description": "Zero rated", "quantity": "1", "price": "40.00" }
+            ]
+        }))
+        .expect("estimate");
+
+        assert_eq!(
+            estimate.computed_totals(),
+            ItemTotals {
+                sub_total: 349.99,
+                total_tax: 62.0,
+                total: 411.99,
+            }
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! Invoice model helpers.
+
+use serde::{Deserialize, Serialize};
+
+use super::dates::ApiDate;
+
+/// Invoice status filter mapped onto FreeAgent's invoice `view` parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceStatus {
+    /// Draft invoices not yet sent.
+    Draft,
+    /// Invoices scheduled to be emailed.
+    ScheduledToEmail,
+    /// Sent invoices that are not yet due.
+    Open,
+    /// Sent invoices past their due date.
+    Overdue,
+    /// Open or overdue invoices.
+    OpenOrOverdue,
+    /// Open or overdue invoices from recent periods.
+    RecentOpenOrOverdue,
+    /// Fully paid invoices.
+    Paid,
+}
+
+impl InvoiceStatus {
+    /// FreeAgent `view` value for this status.
+    pub fn as_view(self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::ScheduledToEmail => "scheduled_to_email",
+            Self::Open => "open",
+            Self::Overdue => "overdue",
+            Self::OpenOrOverdue => "open_or_overdue",
+            Self::RecentOpenOrOverdue => "recent_open_or_overdue",
+            Self::Paid => "paid",
+        }
+    }
+}
+
+/// Invoice line item as sent in `invoice_items`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InvoiceItem {
+    /// Line description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Unit type (`Hours`, `Days`, `Products`, ...).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<String>,
+    /// Quantity as a decimal string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<String>,
+    /// Unit price as a decimal string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    /// Sales tax rate percentage as a decimal string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sales_tax_rate: Option<String>,
+}
+
+impl InvoiceItem {
+    /// Net line amount (`quantity * price`) rounded to pennies.
+    ///
+    /// Returns `None` when either field is missing or not numeric.
+    pub fn computed_line_amount(&self) -> Option<f64> {
+        let quantity = parse_decimal(self.quantity.as_deref())?;
+        let price = parse_decimal(self.price.as_deref())?;
+        Some(round_money(quantity * price))
+    }
+
+    /// Tax on the line at `rate_percent`, rounded to pennies.
+    ///
+    /// With `inclusive`, the line amount is treated as gross and the tax is
+    /// backed out of it rather than added on top.
+    pub fn computed_tax(&self, rate_percent: f64, inclusive: bool) -> Option<f64> {
+        let amount = self.computed_line_amount()?;
+        let rate = rate_percent / 100.0;
+        let tax = if inclusive {
+            amount - amount / (1.0 + rate)
+        } else {
+            amount * rate
+        };
+        Some(round_money(tax))
+    }
+
+    /// Line-level create violations; comment lines only need a description.
+    fn violations(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if !present(self.description.as_deref()) {
+            errors.push("description is required".to_string());
+        }
+        let is_comment = self
+            .item_type
+            .as_deref()
+            .is_some_and(|kind| kind.trim().eq_ignore_ascii_case("comment"));
+        if is_comment {
+            return errors;
+        }
+        if !present(self.item_type.as_deref()) {
+            errors.push("item_type is required".to_string());
+        }
+        if parse_decimal(self.price.as_deref()).is_none() {
+            errors.push("price must be a number".to_string());
+        }
+        if self.quantity.is_some() && parse_decimal(self.quantity.as_deref()).is_none() {
+            errors.push("quantity must be a number".to_string());
+        }
+        if self.sales_tax_rate.is_some()
+            && !parse_decimal(self.sales_tax_rate.as_deref())
+                .is_some_and(|rate| (0.0..=100.0).contains(&rate))
+        {
+            errors.push("sales_tax_rate must be a percentage between 0 and 100".to_string());
+        }
+        errors
+    }
+
+    /// Tax at the item's own `sales_tax_rate`, added on top of the net amount.
+    pub fn computed_sales_tax(&self) -> Option<f64> {
+        self.computed_tax(parse_decimal(self.sales_tax_rate.as_deref())?, false)
+    }
+}
+
+/// Invoice body sent to `POST /invoices`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Invoice {
+    /// Contact URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact: Option<String>,
+    /// Invoice date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dated_on: Option<ApiDate>,
+    /// Days after `dated_on` the invoice falls due.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_terms_in_days: Option<u32>,
+    /// Invoice reference; FreeAgent assigns one when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// Currency code; defaults to the company currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Line items.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invoice_items: Vec<InvoiceItem>,
+}
+
+impl Invoice {
+    /// Checks the fields FreeAgent requires before an invoice can be created.
+    ///
+    /// Collects every violation rather than stopping at the first.
+    pub fn validate_for_create(&self) -> std::result::Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if !present(self.contact.as_deref()) {
+            errors.push("contact is required".to_string());
+        }
+        if self.dated_on.is_none() {
+            errors.push("dated_on is required".to_string());
+        }
+        if self.payment_terms_in_days.is_none() {
+            errors.push("payment_terms_in_days is required".to_string());
+        }
+        if self.invoice_items.is_empty() {
+            errors.push("at least one invoice item is required".to_string());
+        }
+        for (index, item) in self.invoice_items.iter().enumerate() {
+            errors.extend(
+                item.violations()
+                    .into_iter()
+                    .map(|message| format!("invoice_items[{index}]: {message}")),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Net, tax and gross totals summed from line items.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ItemTotals {
+    /// Sum of net line amounts.
+    pub sub_total: f64,
+    /// Sum of per-line sales tax.
+    pub total_tax: f64,
+    /// `sub_total + total_tax`.
+    pub total: f64,
+}
+
+impl ItemTotals {
+    /// Sums line items, skipping lines without a numeric quantity and price
+    /// (headings and comment lines). Tax is rounded per line, then summed.
+    pub fn from_items(items: &[InvoiceItem]) -> Self {
+        let (sub_total, total_tax) =
+            items
+                .iter()
+                .fold((0.0, 0.0), |(sub_total, total_tax), item| {
+                    match item.computed_line_amount() {
+                        Some(amount) => (
+                            sub_total + amount,
+                            total_tax + item.computed_sales_tax().unwrap_or(0.0),
+                        ),
+                        None => (sub_total, total_tax),
+                    }
+                });
+        let sub_total = round_money(sub_total);
+        let total_tax = round_money(total_tax);
+
+        Self {
+            sub_total,
+            total_tax,
+            total: round_money(sub_total + total_tax),
+        }
+    }
+}
+
+fn present(value: Option<&str>) -> bool {
+    value.is_some_and(|value| !value.trim().is_empty())
+}
+
+fn parse_decimal(value: Option<&str>) -> Option<f64> {
+    value?
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+/// Rounds half away from zero to two decimal places.
+///
+/// The tiny nudge keeps binary artefacts such as `1.005 -> 1.00499..` on the
+/// side a decimal calculation would land.
+fn round_money(value: f64) -> f64 {
+    let scaled = value * 100.0;
+    (scaled + scaled.signum() * 1e-9).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(quantity: &str, price: &str) -> InvoiceItem {
+        InvoiceItem {
+            quantity: Some(quantity.to_string()),
+            price: Some(price.to_string()),
+            sales_tax_rate: Some("20.0".to_string()),
+            ..InvoiceItem::default()
+        }
+    }
+
+    #[test]
+    fn computed_line_amount_rounds_to_pennies() {
+        assert_eq!(item("3", "33.335").computed_line_amount(), Some(100.01));
+        assert_eq!(item("1", "1.005").computed_line_amount(), Some(1.01));
+        assert_eq!(
+            InvoiceItem {
+                price: None,
+                ..item("1", "1")
+            }
+            .computed_line_amount(),
+            None
+        );
+    }
+
+    #[test]
+    fn computed_tax_handles_exclusive_and_inclusive_rates() {
+        let line = item("2", "60.00");
+        assert_eq!(line.computed_tax(20.0, false), Some(24.0));
+        assert_eq!(line.computed_tax(20.0, true), Some(20.0));
+        assert_eq!(line.computed_sales_tax(), Some(24.0));
+        assert_eq!(item("1", "10.00").computed_tax(17.5, true), Some(1.49));
+    }
+
+    #[test]
+    fn validate_for_create_reports_every_violation() {
+        let invoice = Invoice {
+            payment_terms_in_days: Some(30),
+            invoice_items: vec![
+                InvoiceItem {
+                    item_type: Some("Hours".to_string()),
+                    quantity: Some("two".to_string()),
+                    price: Some("50".to_string()),
+                    sales_tax_rate: Some("120".to_string()),
+                    ..InvoiceItem::default()
+                },
+                InvoiceItem {
+                    description: Some("Notes".to_string()),
+                    item_type: Some("Comment".to_string()),
+                    ..InvoiceItem::default()
+                },
+            ],
+            ..Invoice::default()
+        };
+
+        assert_eq!(
+            invoice.validate_for_create(),
+            Err(vec![
+                "contact is required".to_string(),
+                "dated_on is required".to_string(),
+                "invoice_items[0]: description is required".to_string(),
+                "invoice_items[0]: quantity must be a number".to_string(),
+                "invoice_items[0]: sales_tax_rate must be a percentage between 0 and 100"
+                    .to_string(),
+            ])
+        );
+        assert_eq!(
+            Invoice::default()
+                .validate_for_create()
+                .map_err(|e| e.len()),
+            Err(4)
+        );
+    }
+
+    #[test]
+    fn validate_for_create_accepts_a_complete_invoice() {
+        let invoice = Invoice {
+            contact: Some("https://api.freeagent.com/v2/contacts/1".to_string()),
+            dated_on: ApiDate::from_ymd(2026, 1, 15),
+            payment_terms_in_days: Some(30),
+            invoice_items: vec![InvoiceItem {
+                description: Some("Design".to_string()),
+                item_type: Some("Hours".to_string()),
+                ..item("2", "150.00")
+            }],
+            ..Invoice::default()
+        };
+
+        assert_eq!(invoice.validate_for_create(), Ok(()));
+    }
+}
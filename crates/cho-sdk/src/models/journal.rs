@@ -0,0 +1,65 @@
+//! Accounting ledger entry model.
+
+use serde::{Deserialize, Serialize};
+
+use super::dates::ApiDate;
+
+/// One ledger line from `accounting/transactions`.
+///
+/// FreeAgent posts every source item (invoice, bill, journal set, ...) as
+/// per-category lines; this is the closest equivalent of a journal line.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AccountingTransaction {
+    /// Transaction URL.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Posting date.
+    #[serde(default)]
+    pub dated_on: Option<ApiDate>,
+    /// Ledger description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Category URL posted to.
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Category display name.
+    #[serde(default)]
+    pub category_name: Option<String>,
+    /// Nominal code posted to.
+    #[serde(default)]
+    pub nominal_code: Option<String>,
+    /// Signed amount, positive for debits.
+    #[serde(default)]
+    pub debit_value: Option<String>,
+    /// URL of the item that produced the posting.
+    #[serde(default)]
+    pub source_item_url: Option<String>,
+}
+
+impl AccountingTransaction {
+    /// Signed amount as a number, positive for debits.
+    pub fn amount(&self) -> Option<f64> {
+        self.debit_value
+            .as_deref()
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .filter(|value| value.is_finite())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amount_parses_signed_debit_value() {
+        let line: AccountingTransaction = serde_json::from_value(serde_json::json!({
+            "dated_on": "2026-01-31",
+            "nominal_code": "001",
+            "debit_value": "-250.00"
+        }))
+        .expect("ledger line");
+        assert_eq!(line.amount(), Some(-250.0));
+        assert_eq!(line.dated_on, ApiDate::from_ymd(2026, 1, 31));
+        assert_eq!(AccountingTransaction::default().amount(), None);
+    }
+}
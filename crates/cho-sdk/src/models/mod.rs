@@ -2,11 +2,46 @@
 
 use serde::{Deserialize, Serialize};
 
+pub mod bank_transaction;
+pub mod batch;
+pub mod category;
+pub mod company;
+pub mod contact;
+pub mod credit_note;
+pub mod dates;
+pub mod estimate;
+pub mod invoice;
+pub mod journal;
+pub mod payment;
+pub mod report;
+pub mod sales_tax;
+pub mod stock_item;
+pub mod tax_rate;
+
+pub use bank_transaction::{BankTransaction, StatementLine};
+pub use batch::{BatchFailure, BatchResult};
+pub use category::{Category, CategoryClass};
+pub use company::Company;
+pub use contact::{ChargeSalesTax, Contact, ContactAddress, ContactBuilder, ContactDetail};
+pub use credit_note::CreditNoteEmail;
+pub use dates::ApiDate;
+pub use estimate::Estimate;
+pub use invoice::{Invoice, InvoiceItem, InvoiceStatus, ItemTotals};
+pub use journal::AccountingTransaction;
+pub use payment::Payment;
+pub use report::{
+    ProfitAndLossSummary, Report, ReportLine, TrialBalanceLine, TrialBalanceParams,
+    TrialBalanceSummary,
+};
+pub use sales_tax::SalesTaxPeriod;
+pub use stock_item::StockItem;
+pub use tax_rate::{EcStatus, TaxRate};
+
 /// Paginated list result from a FreeAgent resource.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ListResult {
+pub struct ListResult<T = serde_json::Value> {
     /// Collected items.
-    pub items: Vec<serde_json::Value>,
+    pub items: Vec<T>,
     /// Total count from `X-Total-Count` when provided.
     pub total: Option<usize>,
     /// True if more pages are available.
@@ -17,6 +52,70 @@ pub struct ListResult {
     pub per_page: u32,
 }
 
+impl<T> ListResult<T> {
+    /// Iterates over collected items.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Number of collected items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// True when no items were collected.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// True when FreeAgent advertised another page after the last one fetched.
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// Page to request next, or `None` when the listing is exhausted.
+    ///
+    /// Exact for single-page results from `list_page`. A multi-page result
+    /// truncated by [`Pagination::limit`] may have dropped the tail of its
+    /// last page, so resume those with a fresh listing instead.
+    pub fn next_page(&self) -> Option<u32> {
+        if self.has_more {
+            self.page.checked_add(1)
+        } else {
+            None
+        }
+    }
+
+    /// Transforms each item while keeping pagination metadata.
+    pub fn map_items<U>(self, f: impl FnMut(T) -> U) -> ListResult<U> {
+        ListResult {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            has_more: self.has_more,
+            page: self.page,
+            per_page: self.per_page,
+        }
+    }
+}
+
+impl<T> IntoIterator for ListResult<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ListResult<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
 /// Pagination settings for list operations.
 #[derive(Debug, Clone, Copy)]
 pub struct Pagination {
@@ -49,6 +148,19 @@ impl Pagination {
     }
 }
 
+/// Rate-limit quota reported by the most recent API response.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitSnapshot {
+    /// Request allowance for the current window.
+    pub limit: Option<u64>,
+    /// Requests remaining in the current window.
+    pub remaining: Option<u64>,
+    /// Seconds until the window resets.
+    pub reset_secs: Option<u64>,
+    /// Seconds FreeAgent asked callers to wait after a 429.
+    pub retry_after: Option<u64>,
+}
+
 /// Auth token status summary.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenStatus {
@@ -204,3 +316,46 @@ pub struct ReconciliationReport {
     /// Aggregate reconciliation counts.
     pub summary: ReconciliationSummary,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_result_iterates_and_maps_items_keeping_pagination() {
+        let result = ListResult {
+            items: vec![
+                serde_json::json!({ "name": "a" }),
+                serde_json::json!({ "name": "b" }),
+            ],
+            total: Some(5),
+            has_more: true,
+            page: 1,
+            per_page: 2,
+        };
+
+        assert_eq!(result.len(), 2);
+        assert!(!result.is_empty());
+        assert!(result.has_more());
+        assert_eq!(result.next_page(), Some(2));
+        assert_eq!((&result).into_iter().count(), 2);
+
+        let names = result.map_items(|item| item["name"].as_str().unwrap_or_default().to_string());
+        assert_eq!(names.total, Some(5));
+        assert!(names.has_more);
+        assert_eq!(names.per_page, 2);
+        assert_eq!(names.into_iter().collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn next_page_is_none_once_exhausted() {
+        let result = ListResult::<serde_json::Value> {
+            items: Vec::new(),
+            total: Some(0),
+            has_more: false,
+            page: 3,
+            per_page: 100,
+        };
+        assert_eq!(result.next_page(), None);
+    }
+}
@@ -0,0 +1,181 @@
+//! Invoice payment payloads.
+
+use serde::{Deserialize, Serialize};
+
+use super::dates::ApiDate;
+
+/// Invoice payment recorded as a FreeAgent bank transaction explanation.
+///
+/// FreeAgent has no standalone payments endpoint: a payment is an explanation
+/// that links a bank account to the invoice it settles. Payments banked as
+/// one deposit are explanations of the same bank transaction, which plays
+/// the role of a batch payment.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Payment {
+    /// Explanation URL (set by FreeAgent).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Bank transaction (statement line) the payment explains.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bank_transaction: Option<String>,
+    /// Bank account URL the payment was received into.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bank_account: Option<String>,
+    /// Invoice URL settled by the payment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paid_invoice: Option<String>,
+    /// Payment amount as a decimal string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gross_value: Option<String>,
+    /// Payment date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dated_on: Option<ApiDate>,
+}
+
+impl Payment {
+    /// Builds a payment against an invoice received into a bank account.
+    pub fn to_invoice(
+        invoice: impl Into<String>,
+        bank_account: impl Into<String>,
+        amount: impl Into<String>,
+        dated_on: ApiDate,
+    ) -> Self {
+        Self {
+            bank_account: Some(bank_account.into()),
+            paid_invoice: Some(invoice.into()),
+            gross_value: Some(amount.into()),
+            dated_on: Some(dated_on),
+            ..Self::default()
+        }
+    }
+
+    /// Checks fields FreeAgent requires before a payment can be created.
+    ///
+    /// A payment explains either an existing bank transaction or a new entry
+    /// in a bank account, so exactly one of the two links must be set.
+    pub fn validate_for_create(&self) -> std::result::Result<(), String> {
+        match (
+            is_blank(self.bank_transaction.as_deref()),
+            is_blank(self.bank_account.as_deref()),
+        ) {
+            (true, true) => {
+                return Err("bank_transaction or bank_account is required".to_string());
+            }
+            (false, false) => {
+                return Err("set only one of bank_transaction or bank_account".to_string());
+            }
+            _ => {}
+        }
+        if is_blank(self.paid_invoice.as_deref()) {
+            return Err("paid_invoice is required".to_string());
+        }
+
+        let amount = self
+            .gross_value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| "gross_value is required".to_string())?;
+        match amount.parse::<f64>() {
+            Ok(value) if value.is_finite() && value > 0.0 => Ok(()),
+            Ok(_) => Err(format!(
+                "gross_value must be greater than zero, got {amount}"
+            )),
+            Err(_) => Err(format!(
+                "gross_value must be a decimal amount, got {amount}"
+            )),
+        }
+    }
+}
+
+fn is_blank(value: Option<&str>) -> bool {
+    value.is_none_or(|value| value.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> ApiDate {
+        ApiDate::from_ymd(2026, 1, 31).expect("valid date")
+    }
+
+    #[test]
+    fn to_invoice_payment_passes_validation() {
+        let payment = Payment::to_invoice(
+            "https://api.freeagent.com/v2/invoices/1",
+            "https://api.freeagent.com/v2/bank_accounts/2",
+            "120.50",
+            date(),
+        );
+        assert_eq!(payment.validate_for_create(), Ok(()));
+    }
+
+    #[test]
+    fn payment_against_bank_transaction_passes_validation() {
+        let payment = Payment {
+            bank_transaction: Some("https://api.freeagent.com/v2/bank_transactions/3".to_string()),
+            bank_account: None,
+            ..Payment::to_invoice("inv", "bank", "10", date())
+        };
+        assert_eq!(payment.validate_for_create(), Ok(()));
+    }
+
+    #[test]
+    fn validation_rejects_missing_links_and_non_positive_amounts() {
+        let valid = Payment::to_invoice("inv", "bank", "10", date());
+
+        let missing_account = Payment {
+            bank_account: None,
+            ..valid.clone()
+        };
+        assert!(
+            missing_account
+                .validate_for_create()
+                .unwrap_err()
+                .contains("bank_account")
+        );
+
+        let missing_invoice = Payment {
+            paid_invoice: Some("  ".to_string()),
+            ..valid.clone()
+        };
+        assert!(
+            missing_invoice
+                .validate_for_create()
+                .unwrap_err()
+                .contains("paid_invoice")
+        );
+
+        let both_links = Payment {
+            bank_transaction: Some("tx".to_string()),
+            ..valid.clone()
+        };
+        assert!(
+            both_links
+                .validate_for_create()
+                .unwrap_err()
+                .contains("only one")
+        );
+
+        for amount in ["0", "-5.00", "abc"] {
+            let payment = Payment {
+                gross_value: Some(amount.to_string()),
+                ..valid.clone()
+            };
+            assert!(payment.validate_for_create().is_err(), "{amount} accepted");
+        }
+    }
+
+    #[test]
+    fn serialization_skips_unset_fields() {
+        let payment = Payment {
+            paid_invoice: Some("inv".to_string()),
+            ..Payment::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&payment).expect("payment serializes"),
+            serde_json::json!({ "paid_invoice": "inv" })
+        );
+    }
+}
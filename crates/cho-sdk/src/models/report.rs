@@ -0,0 +1,550 @@
+//! Typed views over accounting report responses.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::error::{ChoSdkError, Result};
+use crate::models::dates::ApiDate;
+
+/// Title given to a `less` line that arrives without one.
+pub const UNTITLED_LINE: &str = "(untitled)";
+
+/// One deduction line below operating profit (corporation tax, dividends, ...).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportLine {
+    /// Line title as FreeAgent labels it.
+    pub title: String,
+    /// Line total.
+    pub total: Option<f64>,
+}
+
+/// Parsed `accounting/profit_and_loss/summary` response.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProfitAndLossSummary {
+    /// Period start (`YYYY-MM-DD`).
+    pub from: Option<String>,
+    /// Period end (`YYYY-MM-DD`).
+    pub to: Option<String>,
+    /// Total income.
+    pub income: Option<f64>,
+    /// Total expenses.
+    pub expenses: Option<f64>,
+    /// Income less expenses.
+    pub operating_profit: Option<f64>,
+    /// Deductions listed under `less`.
+    pub less: Vec<ReportLine>,
+    /// Profit after deductions.
+    pub retained_profit: Option<f64>,
+}
+
+const INCOME_KEYS: &[&str] = &["income", "total_income"];
+const EXPENSE_KEYS: &[&str] = &["expenses", "total_expenses"];
+const OPERATING_PROFIT_KEYS: &[&str] = &["operating_profit"];
+const RETAINED_PROFIT_KEYS: &[&str] = &["retained_profit"];
+const KNOWN_KEYS: &[&str] = &[
+    "from",
+    "to",
+    "less",
+    "retained_profit_brought_forward",
+    "retained_profit_carried_forward",
+];
+
+impl ProfitAndLossSummary {
+    /// Parses the response, failing when income or expenses are missing.
+    pub fn from_response(value: &Value) -> Result<Self> {
+        let (summary, warnings) = Self::from_response_lenient(value);
+        if summary.income.is_none() || summary.expenses.is_none() {
+            return Err(ChoSdkError::Parse {
+                message: format!(
+                    "Unrecognised profit and loss layout: {}",
+                    warnings.join("; ")
+                ),
+            });
+        }
+        Ok(summary)
+    }
+
+    /// Best-effort parse that never fails, returning warnings for anything it
+    /// had to guess or could not find.
+    ///
+    /// Accepts the payload with or without its `profit_and_loss_summary`
+    /// wrapper, numeric or string amounts, and the `total_income` and
+    /// `total_expenses` spellings. Operating profit is derived from income and
+    /// expenses when absent. `less` lines without a title are kept under
+    /// [`UNTITLED_LINE`] so their totals still count.
+    pub fn from_response_lenient(value: &Value) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+        let empty = Map::new();
+        let body = match value
+            .get("profit_and_loss_summary")
+            .or_else(|| value.get("profit_and_loss"))
+            .unwrap_or(value)
+            .as_object()
+        {
+            Some(body) => body,
+            None => {
+                warnings.push("response is not a JSON object".to_string());
+                &empty
+            }
+        };
+
+        let mut used = KNOWN_KEYS.to_vec();
+        let mut amount = |label: &str, keys: &'static [&'static str]| {
+            used.extend_from_slice(keys);
+            let found = keys
+                .iter()
+                .find_map(|key| body.get(*key).map(|raw| (*key, raw)));
+            match found {
+                Some((key, raw)) => {
+                    let parsed = parse_amount(raw);
+                    if parsed.is_none() {
+                        warnings.push(format!("{key} is not a numeric amount"));
+                    } else if key != keys[0] {
+                        warnings.push(format!("{label} read from '{key}'"));
+                    }
+                    parsed
+                }
+                None => {
+                    warnings.push(format!("{label} not found"));
+                    None
+                }
+            }
+        };
+
+        let income = amount("income", INCOME_KEYS);
+        let expenses = amount("expenses", EXPENSE_KEYS);
+        let mut operating_profit = amount("operating_profit", OPERATING_PROFIT_KEYS);
+        let retained_profit = amount("retained_profit", RETAINED_PROFIT_KEYS);
+
+        if operating_profit.is_none()
+            && let (Some(income), Some(expenses)) = (income, expenses)
+        {
+            operating_profit = Some(income - expenses);
+            warnings.push("operating_profit derived from income - expenses".to_string());
+        }
+
+        let less = body
+            .get("less")
+            .and_then(Value::as_array)
+            .map(|lines| {
+                lines
+                    .iter()
+                    .enumerate()
+                    .map(|(index, line)| {
+                        let title = line
+                            .get("title")
+                            .and_then(Value::as_str)
+                            .map(str::to_string)
+                            .unwrap_or_else(|| {
+                                warnings.push(format!("less[{index}] has no title"));
+                                UNTITLED_LINE.to_string()
+                            });
+                        ReportLine {
+                            title,
+                            total: line.get("total").and_then(parse_amount),
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        for key in body.keys() {
+            if !used.contains(&key.as_str()) {
+                warnings.push(format!("unrecognised section '{key}'"));
+            }
+        }
+
+        let text = |key: &str| body.get(key).and_then(Value::as_str).map(str::to_string);
+        let summary = Self {
+            from: text("from"),
+            to: text("to"),
+            income,
+            expenses,
+            operating_profit,
+            less,
+            retained_profit,
+        };
+
+        (summary, warnings)
+    }
+}
+
+/// Date range for `accounting/trial_balance/summary`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrialBalanceParams {
+    /// First day of the period.
+    pub from_date: Option<ApiDate>,
+    /// Last day of the period; FreeAgent defaults to today.
+    pub to_date: Option<ApiDate>,
+}
+
+impl TrialBalanceParams {
+    /// Checks that the range is not inverted.
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        match (self.from_date, self.to_date) {
+            (Some(from), Some(to)) if from > to => {
+                Err(format!("from_date {from} is after to_date {to}"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Query pairs for the set dates.
+    pub fn query(&self) -> Vec<(String, String)> {
+        [("from_date", self.from_date), ("to_date", self.to_date)]
+            .into_iter()
+            .filter_map(|(key, date)| date.map(|date| (key.to_string(), date.to_string())))
+            .collect()
+    }
+}
+
+/// One nominal account row of a trial balance.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrialBalanceLine {
+    /// Category URL.
+    pub category: Option<String>,
+    /// Nominal code (`001`, `750-1`, ...).
+    pub nominal_code: Option<String>,
+    /// Category name.
+    pub name: Option<String>,
+    /// Debit balance (zero or positive).
+    pub debit: f64,
+    /// Credit balance (zero or positive).
+    pub credit: f64,
+}
+
+impl TrialBalanceLine {
+    /// Net balance, positive for debits.
+    pub fn net(&self) -> f64 {
+        self.debit - self.credit
+    }
+}
+
+/// Parsed trial balance summary.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrialBalanceSummary {
+    /// Account rows in response order.
+    pub lines: Vec<TrialBalanceLine>,
+}
+
+impl TrialBalanceSummary {
+    /// Parses `trial_balance_summary` rows by field name.
+    ///
+    /// Rows carry either a signed `total` (debits positive) or separate
+    /// `debit`/`credit` amounts, as opening balances do; both are split into
+    /// debit and credit columns. Rows with neither are rejected.
+    pub fn from_response(value: &Value) -> Result<Self> {
+        let rows = value
+            .get("trial_balance_summary")
+            .or_else(|| value.get("trial_balance"))
+            .unwrap_or(value)
+            .as_array()
+            .ok_or_else(|| ChoSdkError::Parse {
+                message: "Trial balance response has no rows".to_string(),
+            })?;
+
+        let mut lines = Vec::with_capacity(rows.len());
+        for (index, row) in rows.iter().enumerate() {
+            let text = |keys: &[&str]| {
+                keys.iter()
+                    .find_map(|key| row.get(*key).and_then(Value::as_str))
+                    .map(str::to_string)
+            };
+            let amount = |key: &str| row.get(key).and_then(parse_amount);
+
+            let (debit, credit) = match (amount("total"), amount("debit"), amount("credit")) {
+                (Some(total), _, _) => (total.max(0.0), (-total).max(0.0)),
+                (None, None, None) => {
+                    return Err(ChoSdkError::Parse {
+                        message: format!("Trial balance row {index} has no amount"),
+                    });
+                }
+                (None, debit, credit) => (debit.unwrap_or(0.0), credit.unwrap_or(0.0)),
+            };
+
+            lines.push(TrialBalanceLine {
+                category: text(&["category"]),
+                nominal_code: text(&["display_nominal_code", "nominal_code"]),
+                name: text(&["name", "description"]),
+                debit,
+                credit,
+            });
+        }
+
+        Ok(Self { lines })
+    }
+
+    /// Sum of debit balances.
+    pub fn total_debits(&self) -> f64 {
+        self.lines.iter().map(|line| line.debit).sum()
+    }
+
+    /// Sum of credit balances.
+    pub fn total_credits(&self) -> f64 {
+        self.lines.iter().map(|line| line.credit).sum()
+    }
+}
+
+/// Any accounting report response, for export as flat tabular rows.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    /// Report body without its single-key wrapper (`balance_sheet`, ...).
+    pub body: Value,
+}
+
+/// Keys naming a report line, in preference order.
+const LINE_LABEL_KEYS: &[&str] = &["name", "title", "description", "category_name"];
+/// Keys holding a report line's amount, in preference order.
+const LINE_AMOUNT_KEYS: &[&str] = &["total", "value", "balance", "amount"];
+
+impl Report {
+    /// Wraps a report response, unwrapping a single top-level object key.
+    pub fn from_response(value: &Value) -> Self {
+        let body = match value.as_object() {
+            Some(object) if object.len() == 1 => match object.values().next() {
+                Some(inner @ Value::Object(_)) => inner.clone(),
+                _ => value.clone(),
+            },
+            _ => value.clone(),
+        };
+        Self { body }
+    }
+
+    /// Flattens the report into `level, label, value` rows, header first.
+    ///
+    /// Nested sections become a label-only row followed by their contents one
+    /// level deeper. Objects carrying a name and an amount (account lines)
+    /// collapse into a single row. Object keys come out in alphabetical
+    /// order; array entries keep response order.
+    pub fn to_flat_rows(&self) -> Vec<Vec<String>> {
+        let mut rows = vec![vec![
+            "level".to_string(),
+            "label".to_string(),
+            "value".to_string(),
+        ]];
+        flatten_into(&self.body, 0, &mut rows);
+        rows
+    }
+}
+
+fn flatten_into(value: &Value, level: usize, rows: &mut Vec<Vec<String>>) {
+    match value {
+        Value::Object(object) => {
+            let line = LINE_LABEL_KEYS
+                .iter()
+                .find_map(|key| object.get(*key).and_then(Value::as_str))
+                .zip(
+                    LINE_AMOUNT_KEYS
+                        .iter()
+                        .find_map(|key| object.get(*key).filter(|value| is_scalar(value))),
+                );
+            if let Some((label, amount)) = line {
+                push_row(rows, level, label, scalar_text(amount));
+                for (key, child) in object {
+                    if !is_scalar(child) {
+                        push_row(rows, level + 1, key, String::new());
+                        flatten_into(child, level + 2, rows);
+                    }
+                }
+                return;
+            }
+
+            for (key, child) in object {
+                if is_scalar(child) {
+                    push_row(rows, level, key, scalar_text(child));
+                } else {
+                    push_row(rows, level, key, String::new());
+                    flatten_into(child, level + 1, rows);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                if is_scalar(item) {
+                    push_row(rows, level, "", scalar_text(item));
+                } else {
+                    flatten_into(item, level, rows);
+                }
+            }
+        }
+        scalar => push_row(rows, level, "", scalar_text(scalar)),
+    }
+}
+
+fn push_row(rows: &mut Vec<Vec<String>>, level: usize, label: &str, value: String) {
+    rows.push(vec![level.to_string(), label.to_string(), value]);
+}
+
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Object(_) | Value::Array(_))
+}
+
+fn scalar_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn parse_amount(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().replace(',', "").parse::<f64>().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_response_reads_standard_summary() {
+        let value = serde_json::json!({
+            "profit_and_loss_summary": {
+                "from": "2025-04-01",
+                "to": "2026-03-31",
+                "income": "12000.00",
+                "expenses": "4500.50",
+                "operating_profit": "7499.50",
+                "less": [{ "title": "Corporation Tax", "total": "1424.91" }],
+                "retained_profit": "6074.59"
+            }
+        });
+
+        let summary = ProfitAndLossSummary::from_response(&value).expect("standard layout");
+        assert_eq!(summary.income, Some(12000.0));
+        assert_eq!(summary.operating_profit, Some(7499.5));
+        assert_eq!(summary.less[0].title, "Corporation Tax");
+        assert_eq!(
+            ProfitAndLossSummary::from_response_lenient(&value).1,
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn lenient_parse_falls_back_and_reports_warnings() {
+        let value = serde_json::json!({
+            "total_income": 1000,
+            "total_expenses": "250.00",
+            "gross_margin": "75%",
+            "less": [{ "total": "10.00" }]
+        });
+
+        let (summary, warnings) = ProfitAndLossSummary::from_response_lenient(&value);
+        assert_eq!(summary.income, Some(1000.0));
+        assert_eq!(summary.expenses, Some(250.0));
+        assert_eq!(summary.operating_profit, Some(750.0));
+        assert!(warnings.iter().any(|w| w.contains("'total_income'")));
+        assert!(warnings.iter().any(|w| w.contains("derived")));
+        assert!(warnings.iter().any(|w| w.contains("'gross_margin'")));
+        assert_eq!(summary.less[0].title, UNTITLED_LINE);
+        assert_eq!(summary.less[0].total, Some(10.0));
+        assert!(warnings.iter().any(|w| w.contains("less[0]")));
+    }
+
+    #[test]
+    fn lenient_parse_does_not_guess_from_unrelated_keys() {
+        let (summary, warnings) = ProfitAndLossSummary::from_response_lenient(
+            &serde_json::json!({ "turnover": "900", "costs": "100", "net_profit": "800" }),
+        );
+        assert_eq!(summary.income, None);
+        assert_eq!(summary.expenses, None);
+        assert_eq!(summary.operating_profit, None);
+        assert!(warnings.iter().any(|w| w.contains("'turnover'")));
+    }
+
+    #[test]
+    fn strict_parse_rejects_layouts_without_income() {
+        let err = ProfitAndLossSummary::from_response(&serde_json::json!({ "expenses": "1" }))
+            .expect_err("income missing");
+        assert!(err.to_string().contains("income not found"));
+    }
+
+    #[test]
+    fn trial_balance_splits_signed_totals_into_debits_and_credits() {
+        let value = serde_json::json!({
+            "trial_balance_summary": [
+                { "category": "https://api.freeagent.com/v2/categories/001", "nominal_code": "001", "name": "Sales", "total": "-1200.0" },
+                { "category": "https://api.freeagent.com/v2/categories/750-1", "nominal_code": "750", "display_nominal_code": "750-1", "name": "Current Account", "total": "1200.0" }
+            ]
+        });
+
+        let summary = TrialBalanceSummary::from_response(&value).expect("summary rows");
+        assert_eq!(summary.lines[0].credit, 1200.0);
+        assert_eq!(summary.lines[0].debit, 0.0);
+        assert_eq!(summary.lines[1].nominal_code.as_deref(), Some("750-1"));
+        assert_eq!(summary.total_debits(), summary.total_credits());
+    }
+
+    #[test]
+    fn trial_balance_reads_separate_debit_credit_columns() {
+        let value = serde_json::json!({
+            "trial_balance_summary": [
+                { "nominal_code": "907", "name": "Capital", "credit": "500.00" },
+                { "nominal_code": "750-1", "name": "Current Account", "debit": 500 }
+            ]
+        });
+
+        let summary = TrialBalanceSummary::from_response(&value).expect("opening balance rows");
+        assert_eq!(summary.lines[0].net(), -500.0);
+        assert_eq!(summary.lines[1].net(), 500.0);
+
+        let err = TrialBalanceSummary::from_response(&serde_json::json!({
+            "trial_balance_summary": [{ "name": "Sales" }]
+        }))
+        .expect_err("row without amount");
+        assert!(err.to_string().contains("row 0"));
+    }
+
+    #[test]
+    fn trial_balance_params_reject_inverted_ranges() {
+        let params = TrialBalanceParams {
+            from_date: ApiDate::from_ymd(2026, 3, 31),
+            to_date: ApiDate::from_ymd(2025, 4, 1),
+        };
+        assert!(params.validate().unwrap_err().contains("after"));
+
+        let params = TrialBalanceParams {
+            to_date: ApiDate::from_ymd(2026, 3, 31),
+            ..TrialBalanceParams::default()
+        };
+        assert_eq!(params.validate(), Ok(()));
+        assert_eq!(
+            params.query(),
+            vec![("to_date".to_string(), "2026-03-31".to_string())]
+        );
+    }
+
+    #[test]
+    fn report_flat_rows_indent_sections_and_collapse_account_lines() {
+        let value = serde_json::json!({
+            "balance_sheet": {
+                "as_at_date": "2026-03-31",
+                "current_assets": {
+                    "accounts": [
+                        { "name": "Current Account", "nominal_code": "750-1", "total": "1200.0" },
+                        { "name": "Debtors", "nominal_code": "681", "total": 300 }
+                    ],
+                    "total": "1500.0"
+                }
+            }
+        });
+
+        let rows = Report::from_response(&value).to_flat_rows();
+        let rows = rows.iter().map(|row| row.join("|")).collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                "level|label|value",
+                "0|as_at_date|2026-03-31",
+                "0|current_assets|",
+                "1|accounts|",
+                "2|Current Account|1200.0",
+                "2|Debtors|300",
+                "1|total|1500.0",
+            ]
+        );
+    }
+}
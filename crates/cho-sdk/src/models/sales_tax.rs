@@ -0,0 +1,131 @@
+//! Sales tax period payloads.
+
+use serde::{Deserialize, Serialize};
+
+use super::dates::ApiDate;
+
+/// Sales tax period for universal (non-UK VAT) FreeAgent companies.
+///
+/// Each period carries up to three rates that apply from `effective_date`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SalesTaxPeriod {
+    /// Period URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Tax label shown on invoices (`GST`, `Sales Tax`, ...).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sales_tax_name: Option<String>,
+    /// Registration status (`Registered` or `Not Registered`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sales_tax_registration_status: Option<String>,
+    /// First rate as a percentage string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sales_tax_rate_1: Option<String>,
+    /// Second rate as a percentage string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sales_tax_rate_2: Option<String>,
+    /// Third rate as a percentage string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sales_tax_rate_3: Option<String>,
+    /// Whether the tax is value-added (reclaimable on purchases).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sales_tax_is_value_added: Option<bool>,
+    /// First day the rates apply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<ApiDate>,
+}
+
+impl SalesTaxPeriod {
+    /// Configured rates in slot order, skipping empty slots.
+    pub fn rates(&self) -> Vec<&str> {
+        [
+            &self.sales_tax_rate_1,
+            &self.sales_tax_rate_2,
+            &self.sales_tax_rate_3,
+        ]
+        .into_iter()
+        .filter_map(|rate| rate.as_deref().map(str::trim))
+        .filter(|rate| !rate.is_empty())
+        .collect()
+    }
+
+    /// Checks fields FreeAgent requires before a period can be created.
+    pub fn validate_for_create(&self) -> std::result::Result<(), String> {
+        if self
+            .sales_tax_name
+            .as_deref()
+            .is_none_or(|name| name.trim().is_empty())
+        {
+            return Err("sales_tax_name is required".to_string());
+        }
+
+        if self.effective_date.is_none() {
+            return Err("effective_date is required".to_string());
+        }
+
+        let rates = self.rates();
+        if rates.is_empty() {
+            return Err("at least one of sales_tax_rate_1..3 is required".to_string());
+        }
+        for rate in rates {
+            match rate.parse::<f64>() {
+                Ok(value) if value.is_finite() && (0.0..=100.0).contains(&value) => {}
+                _ => return Err(format!("sales tax rates must be 0-100 percent, got {rate}")),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn period() -> SalesTaxPeriod {
+        SalesTaxPeriod {
+            sales_tax_name: Some("GST".to_string()),
+            sales_tax_rate_1: Some("15.0".to_string()),
+            effective_date: ApiDate::from_ymd(2026, 4, 1),
+            ..SalesTaxPeriod::default()
+        }
+    }
+
+    #[test]
+    fn validation_accepts_named_period_with_one_rate() {
+        assert_eq!(period().validate_for_create(), Ok(()));
+    }
+
+    #[test]
+    fn validation_rejects_missing_rates_and_dates_and_out_of_range_values() {
+        let no_rates = SalesTaxPeriod {
+            sales_tax_rate_1: Some(" ".to_string()),
+            ..period()
+        };
+        assert!(
+            no_rates
+                .validate_for_create()
+                .unwrap_err()
+                .contains("sales_tax_rate")
+        );
+
+        let no_date = SalesTaxPeriod {
+            effective_date: None,
+            ..period()
+        };
+        assert!(
+            no_date
+                .validate_for_create()
+                .unwrap_err()
+                .contains("effective_date")
+        );
+
+        for rate in ["-1", "100.5", "ten"] {
+            let invalid = SalesTaxPeriod {
+                sales_tax_rate_2: Some(rate.to_string()),
+                ..period()
+            };
+            assert!(invalid.validate_for_create().is_err(), "{rate} accepted");
+        }
+    }
+}
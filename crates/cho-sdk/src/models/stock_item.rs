@@ -0,0 +1,67 @@
+//! Stock item model.
+
+use serde::{Deserialize, Serialize};
+
+/// Stock item with its tracked quantity.
+///
+/// FreeAgent tracks quantity on every stock item, so there is no separate
+/// "tracked as inventory" flag.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StockItem {
+    /// Stock item URL.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Item description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Quantity held when stock tracking started.
+    #[serde(default)]
+    pub opening_quantity: Option<String>,
+    /// Value of the opening quantity.
+    #[serde(default)]
+    pub opening_balance: Option<String>,
+    /// Category URL that cost of sale is booked to.
+    #[serde(default)]
+    pub cost_of_sale_category: Option<String>,
+    /// Current quantity on hand.
+    #[serde(default)]
+    pub stock_on_hand: Option<String>,
+}
+
+impl StockItem {
+    /// Quantity on hand as a number, when present and numeric.
+    pub fn quantity_on_hand(&self) -> Option<f64> {
+        self.stock_on_hand
+            .as_deref()
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .filter(|value| value.is_finite())
+    }
+
+    /// True when quantity on hand is at or below `threshold`.
+    ///
+    /// Items without a readable quantity are not reported as low.
+    pub fn is_low_stock(&self, threshold: f64) -> bool {
+        self.quantity_on_hand()
+            .is_some_and(|quantity| quantity <= threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_low_stock_compares_quantity_on_hand() {
+        let item: StockItem = serde_json::from_value(serde_json::json!({
+            "description": "Widget",
+            "stock_on_hand": "3.0"
+        }))
+        .expect("stock item");
+
+        assert_eq!(item.quantity_on_hand(), Some(3.0));
+        assert!(item.is_low_stock(5.0));
+        assert!(item.is_low_stock(3.0));
+        assert!(!item.is_low_stock(2.0));
+        assert!(!StockItem::default().is_low_stock(100.0));
+    }
+}
@@ -0,0 +1,109 @@
+//! Sales tax rate bands and EC status handling.
+
+use serde::{Deserialize, Serialize};
+
+/// EC status on FreeAgent invoices, bills, and bank explanations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EcStatus {
+    /// Domestic or non-EC trade taxed normally.
+    #[serde(rename = "UK/Non-EC")]
+    UkNonEc,
+    /// Goods traded with an EC business.
+    #[serde(rename = "EC Goods")]
+    EcGoods,
+    /// Services traded with an EC business.
+    #[serde(rename = "EC Services")]
+    EcServices,
+    /// Domestic reverse charge (e.g. construction services).
+    #[serde(rename = "Reverse Charge")]
+    ReverseCharge,
+    /// Digital services taxed at the customer's country rate.
+    #[serde(rename = "EC VAT MOSS")]
+    EcVatMoss,
+}
+
+impl EcStatus {
+    /// True when the customer accounts for the tax, so the document shows none.
+    ///
+    /// FreeAgent posts the notional output and input tax itself; the
+    /// document's own tax is zero.
+    pub fn is_reverse_charge(self) -> bool {
+        matches!(self, Self::EcGoods | Self::EcServices | Self::ReverseCharge)
+    }
+}
+
+/// Sales tax rate band from `ec_moss/sales_tax_rates`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaxRate {
+    /// Band name (`Standard`, `Reduced`, `Zero`, ...).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub band: Option<String>,
+    /// Rate percentage as a decimal string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<String>,
+}
+
+impl TaxRate {
+    /// Rate percentage, when present and numeric.
+    pub fn rate(&self) -> Option<f64> {
+        self.percentage.as_deref()?.trim().parse().ok()
+    }
+
+    /// Rate charged on a document with `ec_status`; zero under reverse charge.
+    pub fn effective_rate(&self, ec_status: EcStatus) -> Option<f64> {
+        if ec_status.is_reverse_charge() {
+            Some(0.0)
+        } else {
+            self.rate()
+        }
+    }
+}
+
+/// Looks up the percentage for a band name, ignoring case.
+pub fn rate_for_band(rates: &[TaxRate], band: &str) -> Option<f64> {
+    let band = band.trim();
+    rates
+        .iter()
+        .find(|rate| {
+            rate.band
+                .as_deref()
+                .is_some_and(|name| name.trim().eq_ignore_ascii_case(band))
+        })
+        .and_then(TaxRate::rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rates() -> Vec<TaxRate> {
+        serde_json::from_value(serde_json::json!([
+            { "band": "Standard", "percentage": "20.0" },
+            { "band": "Reduced", "percentage": "5.0" }
+        ]))
+        .expect("rates parse")
+    }
+
+    #[test]
+    fn rate_for_band_matches_case_insensitively() {
+        let rates = rates();
+        assert_eq!(rate_for_band(&rates, "standard"), Some(20.0));
+        assert_eq!(rate_for_band(&rates, " Reduced "), Some(5.0));
+        assert_eq!(rate_for_band(&rates, "Zero"), None);
+    }
+
+    #[test]
+    fn reverse_charge_statuses_zero_the_effective_rate() {
+        let standard = &rates()[0];
+        let status = |raw: &str| {
+            serde_json::from_value::<EcStatus>(serde_json::json!(raw)).expect("status parses")
+        };
+
+        assert_eq!(standard.effective_rate(status("UK/Non-EC")), Some(20.0));
+        assert_eq!(standard.effective_rate(status("EC VAT MOSS")), Some(20.0));
+        for raw in ["EC Goods", "EC Services", "Reverse Charge"] {
+            assert!(status(raw).is_reverse_charge(), "{raw}");
+            assert_eq!(standard.effective_rate(status(raw)), Some(0.0));
+        }
+    }
+}
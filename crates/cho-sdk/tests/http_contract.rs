@@ -4,15 +4,16 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use chrono::{Duration, Utc};
 use secrecy::SecretString;
 use serde_json::json;
-use wiremock::matchers::{header, method, path, query_param};
+use wiremock::matchers::{body_partial_json, header, method, path, query_param};
 use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
 
-use cho_sdk::api::by_name;
+use cho_sdk::api::{AccountingTransactionQuery, RESOURCES, ResourceSpec, by_name};
 use cho_sdk::auth::{AuthManager, token::StoredTokens};
 use cho_sdk::client::FreeAgentClient;
 use cho_sdk::config::SdkConfig;
 use cho_sdk::error::ChoSdkError;
 use cho_sdk::models::Pagination;
+use cho_sdk::models::{ApiDate, Contact, CreditNoteEmail, StatementLine, TrialBalanceParams};
 
 fn seeded_tokens(access_token: &str, refresh_token: &str) -> StoredTokens {
     StoredTokens {
@@ -36,6 +37,14 @@ async fn build_client(
         .with_max_retries(max_retries)
         .with_allow_writes(allow_writes);
 
+    build_client_with_config(config, access_token, refresh_token).await
+}
+
+async fn build_client_with_config(
+    config: SdkConfig,
+    access_token: &str,
+    refresh_token: &str,
+) -> FreeAgentClient {
     let auth = AuthManager::new(
         "client-id".to_string(),
         SecretString::new("client-secret".to_string().into()),
@@ -111,6 +120,64 @@ async fn list_paginated_fetches_all_pages_when_all_is_true() {
     assert_eq!(result.page, 2);
 }
 
+/// Mounts a two-page fixture for `spec` and asserts the generic list walks both pages.
+async fn assert_spec_paginates(server: &MockServer, client: &FreeAgentClient, spec: ResourceSpec) {
+    let endpoint = format!("/v2/{}", spec.path);
+    let item = |n: u32| json!({ "url": format!("{}/v2/{}/{n}", server.uri(), spec.path) });
+
+    Mock::given(method("GET"))
+        .and(path(endpoint.as_str()))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Total-Count", "2")
+                .insert_header(
+                    "Link",
+                    format!("<{}{endpoint}?page=2>; rel=\"next\"", server.uri()),
+                )
+                .set_body_json(json!({ spec.collection_key: [item(1)] })),
+        )
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(endpoint.as_str()))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ spec.collection_key: [item(2)] })),
+        )
+        .mount(server)
+        .await;
+
+    let result = client
+        .resource(spec)
+        .list(
+            &[],
+            Pagination {
+                per_page: 1,
+                limit: 100,
+                all: true,
+            },
+        )
+        .await
+        .unwrap_or_else(|err| panic!("{} list failed: {err}", spec.name));
+
+    assert_eq!(result.items.len(), 2, "{} items", spec.name);
+    assert_eq!(result.total, Some(2), "{} total", spec.name);
+    assert_eq!(result.page, 2, "{} last page", spec.name);
+    assert!(!result.has_more, "{} has_more", spec.name);
+}
+
+#[tokio::test]
+async fn every_listable_resource_spec_walks_link_pagination() {
+    let server = MockServer::start().await;
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+
+    for spec in RESOURCES.iter().filter(|spec| spec.capabilities.list) {
+        assert_spec_paginates(&server, &client, *spec).await;
+    }
+}
+
 #[tokio::test]
 async fn list_paginated_respects_limit_and_sets_has_more() {
     let server = MockServer::start().await;
@@ -198,6 +265,47 @@ async fn get_json_refreshes_on_unauthorized_and_retries_with_new_token() {
     assert_eq!(body["company"]["name"], "Acme Ltd");
 }
 
+#[tokio::test]
+async fn access_token_inside_refresh_margin_is_refreshed_once_then_reused() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "new-access",
+            "token_type": "bearer",
+            "expires_in": 3600,
+            "refresh_token": "new-refresh"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let auth = AuthManager::new(
+        "client-id".to_string(),
+        SecretString::new("client-secret".to_string().into()),
+        SdkConfig::default().with_token_url(format!("{}/oauth/token", server.uri())),
+    )
+    .expect("auth manager must build")
+    .with_token_persistence(false);
+    auth.set_tokens_in_memory(StoredTokens {
+        expires_at: Utc::now() + Duration::seconds(30),
+        ..seeded_tokens("old-access", "old-refresh")
+    })
+    .await;
+
+    for _ in 0..3 {
+        assert_eq!(auth.get_access_token().await.expect("token"), "new-access");
+    }
+
+    auth.set_tokens_in_memory(seeded_tokens("seeded-access", "seeded-refresh"))
+        .await;
+    assert_eq!(
+        auth.get_access_token().await.expect("token"),
+        "seeded-access"
+    );
+}
+
 #[derive(Clone)]
 struct RateLimitThenSuccess {
     calls: Arc<AtomicUsize>,
@@ -240,6 +348,39 @@ async fn get_json_retries_after_rate_limit_and_succeeds() {
     assert_eq!(body["company"]["name"], "After Retry Ltd");
 }
 
+#[tokio::test]
+async fn retry_budget_stops_rate_limit_retries_before_attempts_run_out() {
+    let server = MockServer::start().await;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    Mock::given(method("GET"))
+        .and(path("/v2/company"))
+        .respond_with(move |_request: &Request| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            ResponseTemplate::new(429).insert_header("Retry-After", "1")
+        })
+        .mount(&server)
+        .await;
+
+    let config = SdkConfig::default()
+        .with_base_url(format!("{}/v2/", server.uri()))
+        .with_token_url(format!("{}/oauth/token", server.uri()))
+        .with_max_retries(5)
+        .with_max_retry_duration(std::time::Duration::from_millis(1500));
+    let client = build_client_with_config(config, "seed-access", "seed-refresh").await;
+
+    let started = std::time::Instant::now();
+    let err = client
+        .get_json("company", &[])
+        .await
+        .expect_err("retry budget should surface the rate limit");
+
+    assert!(matches!(err, ChoSdkError::RateLimited { retry_after: 1 }));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert!(started.elapsed() < std::time::Duration::from_secs(3));
+}
+
 #[tokio::test]
 async fn get_bytes_fetches_binary_payload_without_json_parsing() {
     let server = MockServer::start().await;
@@ -264,6 +405,31 @@ async fn get_bytes_fetches_binary_payload_without_json_parsing() {
     assert_eq!(bytes, pdf_bytes);
 }
 
+#[tokio::test]
+async fn get_json_maps_forbidden_response_to_forbidden_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/bills"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "errors": { "error": { "message": "Access denied" } }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 2, false).await;
+    let err = client
+        .get_json("bills", &[])
+        .await
+        .expect_err("403 should surface as an error");
+
+    match err {
+        ChoSdkError::Forbidden { message } => assert_eq!(message, "Access denied"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
 #[tokio::test]
 async fn list_paginated_errors_when_collection_key_is_missing() {
     let server = MockServer::start().await;
@@ -333,3 +499,894 @@ async fn resource_get_rejects_absolute_id_with_untrusted_origin() {
         other => panic!("expected config error, got {other}"),
     }
 }
+
+#[tokio::test]
+async fn invoices_list_for_contact_expands_contact_id_to_base_url() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/invoices"))
+        .and(query_param(
+            "contact",
+            format!("{}/v2/contacts/42", server.uri()),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "invoices": [{"url": "https://api.freeagent.com/v2/invoices/1"}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    let result = client
+        .invoices()
+        .list_for_contact("42", Pagination::default())
+        .await
+        .expect("contact filter should succeed");
+
+    assert_eq!(result.items.len(), 1);
+}
+
+#[tokio::test]
+async fn contacts_create_many_reports_per_row_failures_and_keeps_going() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/contacts"))
+        .and(body_partial_json(
+            json!({ "contact": { "organisation_name": "Rejected Ltd" } }),
+        ))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "errors": { "error": { "message": "Email is invalid" } }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/contacts"))
+        .and(body_partial_json(json!({ "contact": { "organisation_name": "Acme Ltd" } })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "contact": { "url": format!("{}/v2/contacts/1", server.uri()), "organisation_name": "Acme Ltd" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let named = |name: &str| Contact::builder().organisation_name(name);
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, true).await;
+    let result = client
+        .contacts()
+        .create_many(&[
+            named("Rejected Ltd").email("nope").build().expect("named"),
+            Contact {
+                email: Some("nameless@example.com".to_string()),
+                ..Contact::default()
+            },
+            named("Acme Ltd").build().expect("named"),
+        ])
+        .await
+        .expect("batch should complete");
+
+    assert_eq!(result.succeeded.len(), 1);
+    assert_eq!(result.succeeded[0]["organisation_name"], "Acme Ltd");
+    let failed = result
+        .failed
+        .iter()
+        .map(|failure| failure.index)
+        .collect::<Vec<_>>();
+    assert_eq!(failed, vec![0, 1]);
+    assert!(result.failed[1].error.contains("invalid contact"));
+}
+
+#[tokio::test]
+async fn contacts_create_many_stops_after_rate_limit() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/contacts"))
+        .and(body_partial_json(
+            json!({ "contact": { "organisation_name": "First Ltd" } }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "contact": { "organisation_name": "First Ltd" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/contacts"))
+        .and(body_partial_json(
+            json!({ "contact": { "organisation_name": "Second Ltd" } }),
+        ))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "30"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/contacts"))
+        .and(body_partial_json(
+            json!({ "contact": { "organisation_name": "Third Ltd" } }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "contact": {} })))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let named = |name: &str| {
+        Contact::builder()
+            .organisation_name(name)
+            .build()
+            .expect("named contact")
+    };
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, true).await;
+    let result = client
+        .contacts()
+        .create_many(&[named("First Ltd"), named("Second Ltd"), named("Third Ltd")])
+        .await
+        .expect("batch should return partial results");
+
+    assert_eq!(result.succeeded.len(), 1);
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].index, 1);
+    assert_eq!(result.not_attempted, vec![2]);
+    assert!(!result.is_complete());
+}
+
+#[tokio::test]
+async fn contacts_get_full_parses_read_only_contact_fields() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "contact": {
+                "url": format!("{}/v2/contacts/42", server.uri()),
+                "organisation_name": "Acme Ltd",
+                "status": "Active",
+                "account_balance": "250.00",
+                "active_projects_count": 1
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    let detail = client
+        .contacts()
+        .get_full("42")
+        .await
+        .expect("contact should parse");
+
+    assert_eq!(
+        detail.contact.organisation_name.as_deref(),
+        Some("Acme Ltd")
+    );
+    assert!(detail.is_active());
+    assert_eq!(detail.balance(), Some(250.0));
+}
+
+#[tokio::test]
+async fn payments_for_batch_returns_invoice_explanations_of_one_deposit() {
+    let server = MockServer::start().await;
+    let transaction_url = format!("{}/v2/bank_transactions/9", server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/v2/bank_transactions/9"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "bank_transaction": {
+                "url": transaction_url,
+                "amount": "300.0",
+                "bank_transaction_explanations": [
+                    { "url": "e-1", "paid_invoice": "inv-1", "gross_value": "100.0" },
+                    { "url": "e-2", "paid_invoice": "inv-2", "gross_value": "200.0" },
+                    { "url": "e-3", "category": "cat-1", "gross_value": "0.0" }
+                ]
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    let payments = client
+        .payments()
+        .for_batch("9")
+        .await
+        .expect("batch payments should parse");
+
+    let invoices = payments
+        .iter()
+        .map(|payment| payment.paid_invoice.as_deref().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(invoices, vec!["inv-1", "inv-2"]);
+    assert!(
+        payments
+            .iter()
+            .all(|payment| payment.bank_transaction.as_deref() == Some(transaction_url.as_str()))
+    );
+}
+
+#[tokio::test]
+async fn bank_transactions_create_many_uploads_valid_statement_lines() {
+    let server = MockServer::start().await;
+    let account_url = format!("{}/v2/bank_accounts/7", server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/v2/bank_transactions/statement"))
+        .and(query_param("bank_account", account_url.as_str()))
+        .and(body_partial_json(json!({
+            "statement": [
+                { "dated_on": "2026-02-01", "amount": "-42.10", "description": "Card payment", "fitid": "TX-1" }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let line = |amount: &str, fitid: Option<&str>| StatementLine {
+        dated_on: ApiDate::from_ymd(2026, 2, 1).expect("valid date"),
+        amount: amount.to_string(),
+        description: "Card payment".to_string(),
+        fitid: fitid.map(str::to_string),
+    };
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, true).await;
+    let result = client
+        .bank_transactions()
+        .create_many("7", &[line("0", None), line("-42.10", Some("TX-1"))])
+        .await
+        .expect("statement upload should succeed");
+
+    assert!(result.succeeded.is_empty());
+    assert_eq!(result.submitted, vec![1]);
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].index, 0);
+    assert!(result.failed[0].error.contains("invalid statement line"));
+}
+
+#[tokio::test]
+async fn cloned_clients_share_rate_limit_state() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/company"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-RateLimit-Limit", "120")
+                .insert_header("X-RateLimit-Remaining", "7")
+                .set_body_json(json!({ "company": {} })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    let handle = client.clone();
+    tokio::spawn(async move { handle.get_json("company", &[]).await })
+        .await
+        .expect("task should join")
+        .expect("request should succeed");
+
+    let snapshot = client
+        .rate_limit_snapshot()
+        .expect("clone should update shared snapshot");
+    assert_eq!(snapshot.remaining, Some(7));
+    assert_eq!(snapshot.limit, Some(120));
+}
+
+#[tokio::test]
+async fn reports_trial_balance_sends_range_and_parses_rows() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/accounting/trial_balance/summary"))
+        .and(query_param("from_date", "2025-04-01"))
+        .and(query_param("to_date", "2026-03-31"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "trial_balance_summary": [
+                { "nominal_code": "001", "name": "Sales", "total": "-900.0" },
+                { "nominal_code": "750", "display_nominal_code": "750-1", "name": "Current Account", "total": "900.0" }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    let summary = client
+        .reports()
+        .trial_balance(&TrialBalanceParams {
+            from_date: ApiDate::from_ymd(2025, 4, 1),
+            to_date: ApiDate::from_ymd(2026, 3, 31),
+        })
+        .await
+        .expect("trial balance should parse");
+    assert_eq!(summary.lines.len(), 2);
+    assert_eq!(summary.total_credits(), 900.0);
+
+    let err = client
+        .reports()
+        .trial_balance(&TrialBalanceParams {
+            from_date: ApiDate::from_ymd(2026, 4, 1),
+            to_date: ApiDate::from_ymd(2026, 3, 31),
+        })
+        .await
+        .expect_err("inverted range should be rejected locally");
+    assert!(matches!(err, ChoSdkError::Config { .. }));
+}
+
+#[tokio::test]
+async fn reports_get_by_id_resolves_catalog_names_before_requesting() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/accounting/balance_sheet"))
+        .and(query_param("as_at_date", "2026-03-31"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "balance_sheet": { "capital_assets": { "total": "0.0" } }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    let reports = client.reports();
+    assert!(
+        reports
+            .list()
+            .iter()
+            .any(|meta| meta.name == "balance-sheet")
+    );
+
+    let query = vec![("as_at_date".to_string(), "2026-03-31".to_string())];
+    let value = reports
+        .get_by_id("balance-sheet", &query)
+        .await
+        .expect("catalog report should resolve");
+    assert!(value.get("balance_sheet").is_some());
+
+    let err = reports
+        .get_by_id("contacts", &[])
+        .await
+        .expect_err("non-report paths should be rejected locally");
+    assert!(matches!(err, ChoSdkError::Config { .. }));
+}
+
+#[tokio::test]
+async fn accounting_transactions_stream_all_follows_link_pagination() {
+    let server = MockServer::start().await;
+    let line = |n: usize| json!({ "nominal_code": "001", "debit_value": format!("-{n}.00") });
+
+    Mock::given(method("GET"))
+        .and(path("/v2/accounting/transactions"))
+        .and(query_param("from_date", "2025-04-01"))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!(
+                        "<{}/v2/accounting/transactions?page=2>; rel=\"next\"",
+                        server.uri()
+                    ),
+                )
+                .set_body_json(json!({ "transactions": (0..3).map(line).collect::<Vec<_>>() })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/accounting/transactions"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "transactions": (0..100).map(line).collect::<Vec<_>>() })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    let mut pages = client
+        .accounting_transactions()
+        .stream_all(AccountingTransactionQuery {
+            from_date: ApiDate::from_ymd(2025, 4, 1),
+            ..AccountingTransactionQuery::default()
+        });
+
+    let mut sizes = Vec::new();
+    while let Some(page) = pages.next_page().await.expect("page should load") {
+        sizes.push(page.len());
+    }
+    assert_eq!(sizes, vec![3, 100]);
+}
+
+#[tokio::test]
+async fn credit_notes_fetch_pdf_and_send_email() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/credit_notes/9/pdf"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "pdf": { "content": "JVBERi0xLjc=" } })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/credit_notes/9/send_email"))
+        .and(body_partial_json(json!({
+            "credit_note": { "email": { "to": "accounts@acme.test", "subject": "Your refund" } }
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, true).await;
+    let credit_notes = client.credit_notes();
+
+    let pdf = credit_notes.get_pdf("9").await.expect("pdf should decode");
+    assert_eq!(pdf, b"%PDF-1.7".to_vec());
+
+    credit_notes
+        .email(
+            "9",
+            &CreditNoteEmail {
+                to: Some("accounts@acme.test".to_string()),
+                subject: Some("Your refund".to_string()),
+                ..CreditNoteEmail::default()
+            },
+        )
+        .await
+        .expect("email should send");
+
+    let err = credit_notes
+        .email(
+            "9",
+            &CreditNoteEmail {
+                to: Some("accounts".to_string()),
+                ..CreditNoteEmail::default()
+            },
+        )
+        .await
+        .expect_err("invalid recipient should be rejected locally");
+    assert!(matches!(err, ChoSdkError::Validation { .. }));
+}
+
+#[tokio::test]
+async fn list_page_fetches_one_page_and_reports_next_page() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/contacts"))
+        .and(query_param("page", "2"))
+        .and(query_param("per_page", "25"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Total-Count", "60")
+                .insert_header(
+                    "Link",
+                    format!("<{}/v2/contacts?page=3>; rel=\"next\"", server.uri()).as_str(),
+                )
+                .set_body_json(json!({ "contacts": [{ "url": "c-26" }] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    let spec = by_name("contacts").expect("contacts spec");
+    let result = client
+        .resource(spec)
+        .list_page(&[], 2, 25)
+        .await
+        .expect("page should load");
+
+    assert_eq!(result.total, Some(60));
+    assert!(result.has_more());
+    assert_eq!(result.next_page(), Some(3));
+}
+
+#[tokio::test]
+async fn token_refresh_retries_after_token_endpoint_timeout() {
+    let server = MockServer::start().await;
+    let token_body = json!({
+        "access_token": "new-access",
+        "token_type": "bearer",
+        "expires_in": 3600,
+        "refresh_token": "new-refresh"
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(token_body.clone())
+                .set_delay(std::time::Duration::from_secs(2)),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(token_body))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut config = SdkConfig::default()
+        .with_token_url(format!("{}/oauth/token", server.uri()))
+        .with_max_retries(1);
+    config.timeout = std::time::Duration::from_millis(200);
+    let auth = AuthManager::new(
+        "client-id".to_string(),
+        SecretString::new("client-secret".to_string().into()),
+        config,
+    )
+    .expect("auth manager must build")
+    .with_token_persistence(false);
+    auth.set_tokens_in_memory(seeded_tokens("old-access", "old-refresh"))
+        .await;
+
+    auth.refresh()
+        .await
+        .expect("refresh should retry and succeed");
+
+    assert_eq!(auth.get_access_token().await.expect("token"), "new-access");
+}
+
+#[tokio::test]
+async fn report_timeout_override_applies_to_report_requests_only() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/accounting/trial_balance/summary"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "trial_balance_summary": [] }))
+                .set_delay(std::time::Duration::from_secs(2)),
+        )
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "access", "refresh", 0, false).await;
+
+    let err = client
+        .reports()
+        .with_timeout(std::time::Duration::from_millis(200))
+        .trial_balance(&TrialBalanceParams::default())
+        .await
+        .expect_err("report should time out");
+    assert!(
+        matches!(&err, ChoSdkError::Network(inner) if inner.is_timeout()),
+        "{err:?}"
+    );
+
+    let summary = client
+        .reports()
+        .trial_balance(&TrialBalanceParams::default())
+        .await
+        .expect("default timeout should allow the slow report");
+    assert!(summary.lines.is_empty());
+}
+
+#[derive(Clone)]
+struct RateLimitWithRetryAfter {
+    calls: Arc<AtomicUsize>,
+    retry_after: &'static str,
+}
+
+impl Respond for RateLimitWithRetryAfter {
+    fn respond(&self, _request: &Request) -> ResponseTemplate {
+        if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+            ResponseTemplate::new(429).insert_header("Retry-After", self.retry_after)
+        } else {
+            ResponseTemplate::new(200).set_body_json(json!({ "company": {} }))
+        }
+    }
+}
+
+async fn time_rate_limited_retry(
+    retry_after: &'static str,
+    config: SdkConfig,
+) -> std::time::Duration {
+    let server = MockServer::start().await;
+    let calls = Arc::new(AtomicUsize::new(0));
+    Mock::given(method("GET"))
+        .and(path("/v2/company"))
+        .respond_with(RateLimitWithRetryAfter {
+            calls: Arc::clone(&calls),
+            retry_after,
+        })
+        .mount(&server)
+        .await;
+
+    let config = config
+        .with_base_url(format!("{}/v2/", server.uri()))
+        .with_token_url(format!("{}/oauth/token", server.uri()))
+        .with_max_retries(1);
+    let client = build_client_with_config(config, "seed-access", "seed-refresh").await;
+
+    let started = std::time::Instant::now();
+    client
+        .get_json("company", &[])
+        .await
+        .expect("request should succeed after retry");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    started.elapsed()
+}
+
+#[tokio::test]
+async fn retry_after_above_cap_waits_only_max_retry_after() {
+    let elapsed = time_rate_limited_retry(
+        "3600",
+        SdkConfig::default().with_max_retry_after(std::time::Duration::from_millis(100)),
+    )
+    .await;
+    assert!(elapsed < std::time::Duration::from_secs(5), "{elapsed:?}");
+}
+
+#[tokio::test]
+async fn retry_after_zero_waits_min_retry_after() {
+    let elapsed = time_rate_limited_retry(
+        "0",
+        SdkConfig::default().with_min_retry_after(std::time::Duration::from_millis(300)),
+    )
+    .await;
+    assert!(
+        elapsed >= std::time::Duration::from_millis(300),
+        "{elapsed:?}"
+    );
+}
+
+#[tokio::test]
+async fn list_with_progress_reports_each_page() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/invoices"))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Total-Count", "3")
+                .insert_header(
+                    "Link",
+                    format!("<{}/v2/invoices?page=2>; rel=\"next\"", server.uri()),
+                )
+                .set_body_json(json!({ "invoices": [{ "url": "i-1" }, { "url": "i-2" }] })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/invoices"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "invoices": [{ "url": "i-3" }] })),
+        )
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    let spec = by_name("invoices").expect("invoices spec");
+    let mut seen = Vec::new();
+    let result = client
+        .resource(spec)
+        .list_with_progress(
+            &[],
+            Pagination {
+                per_page: 2,
+                ..Pagination::all()
+            },
+            |progress| {
+                seen.push((
+                    progress.collection.clone(),
+                    progress.pages_fetched,
+                    progress.items_so_far,
+                    progress.total,
+                ))
+            },
+        )
+        .await
+        .expect("listing should succeed");
+
+    assert_eq!(result.items.len(), 3);
+    assert_eq!(
+        seen,
+        vec![
+            ("invoices".to_string(), 1, 2, Some(3)),
+            ("invoices".to_string(), 2, 3, Some(3)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn invoices_create_rejects_invalid_invoice_before_sending() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoices"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "invoice": {} })))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, true).await;
+    let invoice = cho_sdk::models::Invoice {
+        payment_terms_in_days: Some(30),
+        invoice_items: vec![cho_sdk::models::InvoiceItem::default()],
+        ..cho_sdk::models::Invoice::default()
+    };
+    let error = client
+        .invoices()
+        .create(&invoice)
+        .await
+        .expect_err("invalid invoice should be rejected locally");
+
+    let ChoSdkError::Validation { resource, errors } = &error else {
+        panic!("expected validation error, got {error:?}");
+    };
+    assert_eq!(resource, "invoice");
+    for expected in [
+        "contact is required",
+        "dated_on is required",
+        "invoice_items[0]: description is required",
+        "invoice_items[0]: price must be a number",
+    ] {
+        assert!(
+            errors.iter().any(|error| error.contains(expected)),
+            "{expected} missing from {errors:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn default_headers_are_sent_with_every_request() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/company"))
+        .and(header("x-correlation-id", "run-42"))
+        .and(header("accept", "application/vnd.test+json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "company": {} })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let config = SdkConfig::default()
+        .with_base_url(format!("{}/v2/", server.uri()))
+        .with_token_url(format!("{}/oauth/token", server.uri()))
+        .with_max_retries(0)
+        .with_default_header("X-Correlation-Id", "run-42")
+        .with_default_header("Accept", "application/vnd.test+json");
+    let client = build_client_with_config(config, "seed-access", "seed-refresh").await;
+
+    client
+        .get_json("company", &[])
+        .await
+        .expect("request with default headers should succeed");
+}
+
+#[tokio::test]
+async fn default_headers_cannot_override_authorization() {
+    let auth = AuthManager::new(
+        "client-id".to_string(),
+        SecretString::new("client-secret".to_string().into()),
+        SdkConfig::default(),
+    )
+    .expect("auth manager must build");
+
+    let result = FreeAgentClient::builder()
+        .config(SdkConfig::default().with_default_header("Authorization", "Bearer other"))
+        .auth_manager(auth)
+        .build();
+
+    match result {
+        Err(ChoSdkError::Config { message }) => assert!(message.contains("authorization")),
+        Err(other) => panic!("unexpected error: {other}"),
+        Ok(_) => panic!("authorization override should be rejected"),
+    }
+}
+
+#[tokio::test]
+async fn invoices_mark_sent_puts_transition_without_body() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v2/invoices/42/transitions/mark_as_sent"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "invoice": { "url": format!("{}/v2/invoices/42", server.uri()), "status": "Open" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, true).await;
+    let value = client
+        .invoices()
+        .mark_sent("42")
+        .await
+        .expect("mark_sent should succeed");
+
+    assert_eq!(value["invoice"]["status"], "Open");
+
+    let read_only = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    assert!(matches!(
+        read_only.invoices().mark_sent("42").await,
+        Err(ChoSdkError::WriteNotAllowed { .. })
+    ));
+}
+
+#[tokio::test]
+async fn login_code_exchange_does_not_report_invalid_grant_as_revoked_refresh_token() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": "invalid_grant",
+            "error_description": "The authorization code has expired"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    let err = client
+        .auth()
+        .exchange_authorization_code("stale-code", "http://127.0.0.1:8123/callback")
+        .await
+        .expect_err("expired authorization code should fail");
+
+    match err {
+        ChoSdkError::ApiError {
+            status, message, ..
+        } => {
+            assert_eq!(status, 400);
+            assert!(message.contains("authorization code has expired"));
+            assert!(!message.contains("refresh token"));
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+}
+
+#[tokio::test]
+async fn refresh_reports_invalid_grant_as_token_expired_with_server_detail() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": "invalid_grant",
+            "error_description": "The refresh token has been revoked"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_client(&server, "seed-access", "seed-refresh", 0, false).await;
+    let err = client
+        .auth()
+        .force_refresh()
+        .await
+        .expect_err("revoked refresh token should fail");
+
+    match err {
+        ChoSdkError::TokenExpired { message } => {
+            assert!(message.contains("invalid_grant"));
+            assert!(message.contains("has been revoked"));
+            assert!(message.contains("cho auth login"));
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+}
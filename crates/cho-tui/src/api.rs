@@ -8,6 +8,7 @@ use cho_sdk::api::specs::{ResourceSpec, by_name};
 use cho_sdk::auth::AuthManager;
 use cho_sdk::client::{FreeAgentClient, RequestPolicy};
 use cho_sdk::error::ChoSdkError;
+use cho_sdk::models::category::flatten_category_groups;
 use cho_sdk::models::{ListResult, Pagination, TokenStatus};
 use chrono::{DateTime, Datelike, NaiveDate};
 use secrecy::SecretString;
@@ -1182,42 +1183,6 @@ struct CappedItems {
     has_more: bool,
 }
 
-fn flatten_category_groups(value: &serde_json::Value) -> Vec<serde_json::Value> {
-    let Some(object) = value.as_object() else {
-        return Vec::new();
-    };
-
-    let mut out = Vec::new();
-    for (group_name, group_value) in object {
-        if let Some(items) = group_value.as_array() {
-            for item in items {
-                let mut item_value = item.clone();
-                if let serde_json::Value::Object(map) = &mut item_value
-                    && !map.contains_key("category_group")
-                {
-                    map.insert(
-                        "category_group".to_string(),
-                        serde_json::Value::String(group_name.clone()),
-                    );
-                }
-                out.push(item_value);
-            }
-        } else if group_value.is_object() {
-            let mut item_value = group_value.clone();
-            if let serde_json::Value::Object(map) = &mut item_value
-                && !map.contains_key("category_group")
-            {
-                map.insert(
-                    "category_group".to_string(),
-                    serde_json::Value::String(group_name.clone()),
-                );
-            }
-            out.push(item_value);
-        }
-    }
-    out
-}
-
 fn user_id_segment(user: &str) -> String {
     let trimmed = user.trim().trim_end_matches('/');
     if (trimmed.starts_with("https://") || trimmed.starts_with("http://"))
@@ -1346,11 +1311,13 @@ fn _error_code(err: &ChoSdkError) -> &'static str {
         ChoSdkError::AuthRequired { .. } => "AUTH_REQUIRED",
         ChoSdkError::TokenExpired { .. } => "TOKEN_EXPIRED",
         ChoSdkError::RateLimited { .. } => "RATE_LIMITED",
+        ChoSdkError::Forbidden { .. } => "FORBIDDEN",
         ChoSdkError::NotFound { .. } => "NOT_FOUND",
         ChoSdkError::ApiError { .. } => "API_ERROR",
         ChoSdkError::Network(_) => "NETWORK_ERROR",
         ChoSdkError::Parse { .. } => "PARSE_ERROR",
         ChoSdkError::Config { .. } => "CONFIG_ERROR",
+        ChoSdkError::Validation { .. } => "VALIDATION_ERROR",
         ChoSdkError::WriteNotAllowed { .. } => "WRITE_NOT_ALLOWED",
     }
 }
@@ -1447,8 +1414,7 @@ mod tests {
 
     #[test]
     fn classify_auth_error_ignores_non_auth_failures() {
-        let forbidden = ChoSdkError::ApiError {
-            status: 403,
+        let forbidden = ChoSdkError::Forbidden {
             message: "forbidden".to_string(),
         };
         let config = ChoSdkError::Config {
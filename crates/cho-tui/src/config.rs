@@ -56,6 +56,8 @@ pub struct SdkConfigFile {
     pub timeout_secs: Option<u64>,
     /// Max retries.
     pub max_retries: Option<u32>,
+    /// Wall-clock retry budget per request in seconds.
+    pub max_retry_duration_secs: Option<u64>,
 }
 
 /// Safety config.
@@ -136,6 +138,11 @@ impl AppConfig {
             config = config.with_max_retries(max_retries);
         }
 
+        if let Some(max_retry_duration_secs) = self.sdk.max_retry_duration_secs {
+            config = config
+                .with_max_retry_duration(std::time::Duration::from_secs(max_retry_duration_secs));
+        }
+
         config.with_allow_writes(self.safety.allow_writes)
     }
 